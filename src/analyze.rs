@@ -64,7 +64,13 @@ async fn analyze_with_clang(config: Arc<Config>, args: &[String]) -> Result<(),
     pbar.enable_steady_tick(Duration::from_millis(50));
 
     // Create parser
-    let unit = index.parser(&target_src).arguments(args).parse()?;
+    // A detailed preprocessing record is needed for macro definitions to
+    // show up in the AST
+    let unit = index
+        .parser(&target_src)
+        .arguments(args)
+        .detailed_preprocessing_record(true)
+        .parse()?;
 
     // Build the navbar first
     pbar.set_message("Setting up");
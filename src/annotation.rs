@@ -41,10 +41,6 @@ impl<'a> Annotations<'a> {
         result
     }
 
-    pub fn rewind(&mut self) {
-        self.next_in_iter = 0;
-    }
-
     pub fn next(&mut self) -> Option<String> {
         self.annotations
             .iter()
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::{html::Html, url::UrlPath};
+use clang::Entity;
+
+use super::{
+    builder::Builder,
+    shared::output_alias,
+    traits::{ASTEntry, BuildResult, EntityMethods, Entry, NavItem, OutputEntry},
+};
+
+pub struct Alias<'e> {
+    entity: Entity<'e>,
+}
+
+impl<'e> Alias<'e> {
+    pub fn new(entity: Entity<'e>) -> Self {
+        Self { entity }
+    }
+}
+
+impl<'e> Entry<'e> for Alias<'e> {
+    fn name(&self) -> String {
+        self.entity.get_name().unwrap_or("`Anonymous alias`".into())
+    }
+
+    fn url(&self) -> UrlPath {
+        self.entity.rel_docs_url().expect("Unable to get alias URL")
+    }
+
+    fn build(&self, builder: &Builder<'e>) -> BuildResult {
+        builder.create_output_for(self)
+    }
+
+    fn nav(&self) -> NavItem {
+        NavItem::new_link(&self.name(), self.url(), Some(("type", false)), Vec::new())
+            .deprecated(self.entity.get_deprecation().is_some())
+    }
+}
+
+impl<'e> ASTEntry<'e> for Alias<'e> {
+    fn entity(&self) -> &Entity<'e> {
+        &self.entity
+    }
+
+    fn category(&self) -> &'static str {
+        "alias"
+    }
+}
+
+impl<'e> OutputEntry<'e> for Alias<'e> {
+    fn output(&self, builder: &Builder<'e>) -> (Arc<String>, Vec<(&'static str, Html)>) {
+        (
+            builder.config.templates.alias.clone(),
+            output_alias(self, builder),
+        )
+    }
+
+    fn description(&self, builder: &'e Builder<'e>) -> String {
+        self.output_description(builder)
+    }
+}
@@ -15,9 +15,12 @@ use crate::{
 };
 
 use super::{
+    coverage::{Coverage, CoverageReport},
+    deprecated::DeprecatedIndex,
     files::Root,
     namespace::Namespace,
-    traits::{BuildResult, Entry, OutputEntry},
+    shared::{check_doc_comments, get_all_deprecated, NameIndex},
+    traits::{BuildResult, Entry, NavItem, OutputEntry},
     tutorial::TutorialFolder,
 };
 
@@ -30,6 +33,8 @@ pub struct Builder<'e> {
     file_roots: Vec<Root>,
     tutorials: TutorialFolder,
     nav_cache: Option<String>,
+    coverage: Option<Coverage>,
+    names: NameIndex,
 }
 
 impl<'e> Builder<'e> {
@@ -49,6 +54,8 @@ impl<'e> Builder<'e> {
             file_roots: Root::from_config(config.clone()),
             tutorials: TutorialFolder::from_config(config),
             nav_cache: None,
+            coverage: None,
+            names: NameIndex::default(),
         }
         .setup()
     }
@@ -235,21 +242,40 @@ impl<'e> Builder<'e> {
 
     fn all_entries(&self) -> Vec<&dyn Entry<'e>> {
         self.root
-            .entries
-            .iter()
-            .map(|p| p.1 as &dyn Entry<'e>)
+            .all_entries()
+            .map(|entry| entry as &dyn Entry<'e>)
             .chain(self.file_roots.iter().map(|p| p as &dyn Entry<'e>))
-            .chain([&self.tutorials as &dyn Entry])
+            .chain([
+                &self.tutorials as &dyn Entry,
+                &DeprecatedIndex,
+                &CoverageReport,
+            ])
             .collect()
     }
 
     fn prebuild(&mut self) -> Result<(), String> {
+        // Index names first since resolving references needs them
+        self.names = NameIndex::new(self);
+        check_doc_comments(self);
+
         // Prebuild cached navbars for much faster docs builds
         self.prebuild_nav()?;
 
+        self.coverage = Some(Coverage::new(self));
+
         Ok(())
     }
 
+    pub fn coverage(&self) -> &Coverage {
+        self.coverage
+            .as_ref()
+            .expect("Coverage should have been computed in prebuild")
+    }
+
+    pub fn names(&self) -> &NameIndex {
+        &self.names
+    }
+
     pub async fn build(&self, pbar: Option<Arc<ProgressBar>>) -> Result<(), String> {
         let mut handles = Vec::new();
 
@@ -289,12 +315,15 @@ impl<'e> Builder<'e> {
                     .nav()
                     .suboptions_titles(self.config.clone())
                     .into_iter()
-                    .map(|(n, c)| {
-                        if c > 0 {
-                            format!("{} ({})", n, c + 1)
-                        } else {
-                            n
-                        }
+                    .map(|(n, (c, deprecated))| {
+                        json!({
+                            "name": if c > 0 {
+                                format!("{} ({})", n, c + 1)
+                            } else {
+                                n
+                            },
+                            "deprecated": deprecated,
+                        })
                     })
                     .collect::<Vec<_>>(),
             )
@@ -310,12 +339,36 @@ impl<'e> Builder<'e> {
         .await
         .unwrap();
 
+        tokio::fs::write(
+            self.config.output_dir.join("coverage.json"),
+            serde_json::to_string(&self.coverage().to_json())
+                .map_err(|e| format!("Unable to save coverage report {e}"))?,
+        )
+        .await
+        .map_err(|e| format!("Unable to save coverage report {e}"))?;
+
+        let coverage = self.coverage().total.entities.percentage();
+        if let Some(min) = self.config.min_coverage
+            && coverage < min
+        {
+            return Err(format!(
+                "Documentation coverage is {coverage:.1}%, which is below the minimum of {min}%"
+            ));
+        }
+
         Ok(())
     }
 
     fn build_nav_metadata(&self) -> serde_json::Value {
         let tutorials = self.tutorials.nav().to_json(self.config.clone());
-        let entities = self.root.nav().to_json(self.config.clone());
+        let mut entities = self.root.nav();
+        // only link to the deprecated API page if there's something on it
+        if let NavItem::Root(_, ref mut items) = entities
+            && !get_all_deprecated(self).is_empty()
+        {
+            items.push(DeprecatedIndex.nav());
+        }
+        let entities = entities.to_json(self.config.clone());
         json!({
             "tutorials": tutorials,
             "entities": entities,
@@ -1,21 +1,28 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
-use crate::{html::Html, url::UrlPath};
+use crate::{config::Config, html::Html, url::UrlPath};
 use clang::Entity;
 
 use super::{
     builder::Builder,
+    namespace::{load_member_types, CppItem},
     shared::output_classlike,
     traits::{ASTEntry, BuildResult, EntityMethods, Entry, NavItem, OutputEntry, SubItem},
 };
 
 pub struct Class<'e> {
     entity: Entity<'e>,
+    pub entries: HashMap<String, CppItem<'e>>,
+    suboptions: Vec<SubItem>,
 }
 
 impl<'e> Class<'e> {
-    pub fn new(entity: Entity<'e>) -> Self {
-        Self { entity }
+    pub fn new(entity: Entity<'e>, config: Arc<Config>) -> Self {
+        Self {
+            entity,
+            entries: load_member_types(&entity, config.clone()),
+            suboptions: SubItem::for_classlike(&entity, &config),
+        }
     }
 }
 
@@ -31,16 +38,25 @@ impl<'e> Entry<'e> for Class<'e> {
     }
 
     fn build(&self, builder: &Builder<'e>) -> BuildResult {
-        builder.create_output_for(self)
+        let mut handles = builder.create_output_for(self)?;
+        for entry in self.entries.values() {
+            handles.extend(entry.build(builder)?);
+        }
+        Ok(handles)
     }
 
     fn nav(&self) -> NavItem {
-        NavItem::new_link(
+        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|p| p.0);
+
+        NavItem::new_link_with_items(
             &self.name(),
             self.url(),
             Some(("box", false)),
-            SubItem::for_classlike(&self.entity),
+            self.suboptions.clone(),
+            entries.iter().map(|e| e.1.nav()).collect(),
         )
+        .deprecated(self.entity.get_deprecation().is_some())
     }
 }
 
@@ -58,7 +74,7 @@ impl<'e> OutputEntry<'e> for Class<'e> {
     fn output(&self, builder: &Builder<'e>) -> (Arc<String>, Vec<(&'static str, Html)>) {
         (
             builder.config.templates.class.clone(),
-            output_classlike(self, builder),
+            output_classlike(self, &self.entries, builder),
         )
     }
 
@@ -1,8 +1,8 @@
-use std::{collections::HashMap, fs, str::Chars};
+use std::{collections::HashMap, fs, str::Chars, sync::LazyLock};
 
 use clang::{
     token::{Token, TokenKind},
-    Entity, EntityKind,
+    Entity, EntityKind, Type,
 };
 use log::{error, warn};
 use multipeek::{IteratorExt, MultiPeek};
@@ -13,8 +13,62 @@ use crate::{
 };
 
 use super::{
-    builder::Builder, markdown::fmt_markdown, shared::fmt_autolinks, traits::EntityMethods,
+    builder::Builder,
+    markdown::{fmt_markdown, fmt_markdown_inline},
+    shared::{fmt_autolinks, names_namespace, resolve_name, unresolved_links},
+    traits::EntityMethods,
 };
+use regex_lite::Regex;
+
+/// Commands that can appear in the middle of text, and are handled by
+/// fmt_autolinks and fmt_inline_commands instead of the lexer
+const INLINE_COMMANDS: &[&str] = &["ref", "p", "c", "a", "e", "em", "b"];
+
+/// Commands that can also be written Doxygen-style with a backslash, like
+/// `\param`. Unlike with `@`, a backslash followed by anything else is just
+/// text, so things like `\n` don't need escaping
+const BACKSLASH_COMMANDS: &[&str] = &[
+    "brief",
+    "short",
+    "details",
+    "param",
+    "tparam",
+    "return",
+    "returns",
+    "result",
+    "retval",
+    "throw",
+    "throws",
+    "exception",
+    "see",
+    "sa",
+    "note",
+    "remark",
+    "remarks",
+    "warning",
+    "pre",
+    "post",
+    "todo",
+    "version",
+    "since",
+    "deprecated",
+    "code",
+    "endcode",
+    "example",
+    "internal",
+    "private",
+    "hidden",
+    "inheritdoc",
+    "name",
+    "group",
+    "file",
+    "class",
+    "par",
+    "li",
+    "ingroup",
+    "{",
+    "}",
+];
 
 struct CommentLexer<'s> {
     raw: MultiPeek<Chars<'s>>,
@@ -22,15 +76,26 @@ struct CommentLexer<'s> {
 
 impl<'s> CommentLexer<'s> {
     pub fn new(raw: &'s str) -> Self {
+        let raw = raw.trim_end_matches("*/");
+        // Qt-style `/*!` and `//!` comments have a `!` where JavaDoc-style
+        // ones have a `*` or `/`
+        let raw = raw
+            .strip_prefix("/*!")
+            .or_else(|| raw.strip_prefix("//!"))
+            .unwrap_or_else(|| raw.trim_start_matches("/*"));
         Self {
-            raw: raw
-                .trim_end_matches("*/")
-                .trim_start_matches("/*")
-                .chars()
-                .multipeek(),
+            raw: Self::strip_trailing_marker(raw).chars().multipeek(),
         }
     }
 
+    /// Trailing comments like `///< text` and `/**< text */` have a `<` right
+    /// after the comment start, which isn't a part of the text
+    fn strip_trailing_marker(raw: &str) -> &str {
+        raw.trim_start_matches(['*', '/', '!'])
+            .strip_prefix('<')
+            .unwrap_or(raw)
+    }
+
     fn skip_while<P: FnMut(char) -> bool>(&mut self, mut pred: P) -> usize {
         let mut count = 0;
         while self.raw.peek().is_some_and(|c| pred(*c)) {
@@ -65,6 +130,13 @@ impl<'s> CommentLexer<'s> {
             }
         } else if self.raw.peek() == Some(&'/') {
             self.skip_while(|c| c == '/');
+            // Qt-style `//!` and trailing `///<` comments
+            if self.raw.peek() == Some(&'!') {
+                self.raw.next();
+            }
+            if self.raw.peek() == Some(&'<') {
+                self.raw.next();
+            }
             // If indentation was provided, remove that amount of whitespace if possible
             if let Some(max) = indentation {
                 let mut i = 0;
@@ -93,10 +165,18 @@ impl<'s> CommentLexer<'s> {
     }
 
     fn eat_until<P: FnMut(char) -> bool>(&mut self, mut pred: P) -> Option<String> {
+        self.eat_until_with(|raw| raw.peek().is_some_and(|c| pred(*c)))
+    }
+
+    /// Same as eat_until, but the predicate can look further ahead
+    fn eat_until_with<P: FnMut(&mut MultiPeek<Chars<'s>>) -> bool>(
+        &mut self,
+        mut pred: P,
+    ) -> Option<String> {
         let mut res = String::new();
         let mut indent_size = None;
         while let Some(c) = self.raw.peek().copied() {
-            if pred(c) {
+            if pred(&mut self.raw) {
                 break;
             }
             // On newlines, skip whitespace and the next line's starting star
@@ -127,12 +207,33 @@ impl<'s> CommentLexer<'s> {
 
         // todo: handle escaped @ symbol
 
-        match self.raw.peek()? {
-            '@' => {
+        match self.raw.peek().copied()? {
+            '@' | '\\' if Self::is_block_command(&mut self.raw) => {
                 // Consume param symbol
                 self.raw.next();
-                // Eat command name
-                let cmd = self.eat_until(|c| c.is_whitespace() || c == '[')?;
+                // Eat command name, which may also be the braces of Doxygen
+                // member groups
+                let cmd = match self.raw.peek() {
+                    Some(c @ ('{' | '}')) => {
+                        let cmd = c.to_string();
+                        self.raw.next();
+                        cmd
+                    }
+                    _ => self.eat_until(|c| c.is_whitespace() || c == '[' || c == '{')?,
+                };
+                // Doxygen-style language of a code block, like `@code{.cpp}`
+                if self.raw.peek().is_some_and(|c| *c == '{') {
+                    self.raw.next();
+                    let lang = self.eat_until(|c| c == '}').unwrap_or_default();
+                    self.raw.next();
+                    return Some(ParsedCommand::new_with(
+                        cmd,
+                        HashMap::from([(
+                            "lang".into(),
+                            Some(lang.trim_start_matches('.').to_owned()),
+                        )]),
+                    ));
+                }
                 // Parse attributes if those are provided
                 if self.raw.peek().is_some_and(|c| *c == '[') {
                     // Consume opening bracket
@@ -188,8 +289,71 @@ impl<'s> CommentLexer<'s> {
         self.eat_word()
     }
 
+    /// Checks if the stream has the text at the offset
+    fn is_at(raw: &mut MultiPeek<Chars>, offset: usize, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(i, c)| raw.peek_nth(offset + i) == Some(&c))
+    }
+
+    /// Checks if the stream has one of the commands right after the `@` or
+    /// backslash at the start of it
+    fn is_one_of(raw: &mut MultiPeek<Chars>, commands: &[&str]) -> bool {
+        commands.iter().any(|cmd| {
+            Self::is_at(raw, 1, cmd)
+                && (!cmd.starts_with(char::is_alphanumeric)
+                    || raw
+                        .peek_nth(cmd.len() + 1)
+                        .is_none_or(|c| !c.is_alphanumeric()))
+        })
+    }
+
+    /// Checks if the `@` or backslash at the start of the stream begins a new
+    /// command, and isn't just a part of the text like `@ref` or `\n`
+    fn is_block_command(raw: &mut MultiPeek<Chars>) -> bool {
+        match raw.peek() {
+            Some('@') => !Self::is_one_of(raw, INLINE_COMMANDS),
+            Some('\\') => Self::is_one_of(raw, BACKSLASH_COMMANDS),
+            _ => false,
+        }
+    }
+
     pub fn next_value(&mut self) -> Option<String> {
-        self.eat_until(|c| c == '@')
+        // `{@link Name}` is also a part of the text
+        let mut prev = None;
+        self.eat_until_with(|raw| {
+            let c = raw.peek().copied();
+            let is_command = prev != Some('{') && Self::is_block_command(raw);
+            prev = c;
+            is_command
+        })
+    }
+
+    /// Checks if there is a Doxygen `@endcode` somewhere ahead
+    fn has_code_end(&mut self) -> bool {
+        let mut i = 0;
+        while self.raw.peek_nth(i).is_some() {
+            if Self::is_at(&mut self.raw, i, "@endcode")
+                || Self::is_at(&mut self.raw, i, "\\endcode")
+            {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Eats a Doxygen code block up to its `@endcode`, which may contain
+    /// anything including `@` symbols
+    pub fn next_code_block(&mut self) -> Option<String> {
+        let code = self.eat_until_with(|raw| {
+            Self::is_at(raw, 0, "@endcode") || Self::is_at(raw, 0, "\\endcode")
+        });
+        // Consume @endcode
+        for _ in 0.."@endcode".len() {
+            self.raw.next();
+        }
+        code
     }
 
     pub fn param_for(&mut self, cmd: &ParsedCommand) -> String {
@@ -379,14 +543,22 @@ pub struct Example<'e> {
     builder: &'e Builder<'e>,
     data: String,
     analyze: bool,
+    /// Language of the code, like with `@code{.py}`. C++ if not specified
+    lang: Option<String>,
 }
 
 impl<'e> Example<'e> {
-    pub fn new(data: String, analyze: bool, builder: &'e Builder<'e>) -> Self {
+    pub fn new(
+        data: String,
+        analyze: bool,
+        lang: Option<String>,
+        builder: &'e Builder<'e>,
+    ) -> Self {
         Self {
             builder,
             data,
             analyze,
+            lang,
         }
     }
 
@@ -482,8 +654,10 @@ impl<'e> Example<'e> {
     }
 
     pub fn to_html(&self) -> Html {
+        let lang = self.lang.as_deref().unwrap_or("cpp");
         // Custom syntax highlighting with links
         if self.analyze
+            && matches!(lang, "cpp" | "c++" | "cxx")
             && let Ok(sweet) = self
                 .try_to_analyzed_html()
                 .inspect_err(|e| error!("Unable to parse example: {e}"))
@@ -495,7 +669,7 @@ impl<'e> Example<'e> {
             HtmlElement::new("pre")
                 .with_child(
                     HtmlElement::new("code")
-                        .with_classes(&["example", "language-cpp"])
+                        .with_classes(&["example", &format!("language-{lang}")])
                         .with_text(&self.data),
                 )
                 .into()
@@ -503,15 +677,171 @@ impl<'e> Example<'e> {
     }
 }
 
+static INLINE_COMMAND: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(^|[^\w])[@\\](p|c|a|e|em|b)\s+([\w:~]+(?:\(\))?)").unwrap());
+
+/// Formats Doxygen inline commands as markdown, like `@p name` and `@c code`
+/// as inline code and `@b text` as bold text. Code spans and fenced code
+/// blocks are left as they are
+fn fmt_inline_commands(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut in_fence = false;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let is_fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+        if is_fence {
+            in_fence = !in_fence;
+        }
+        if in_fence || is_fence {
+            res.push_str(line);
+            continue;
+        }
+        // every other part between backticks is a code span
+        for (i, part) in line.split('`').enumerate() {
+            if i > 0 {
+                res.push('`');
+            }
+            if i % 2 == 1 {
+                res.push_str(part);
+                continue;
+            }
+            res.push_str(
+                &INLINE_COMMAND.replace_all(part, |caps: &regex_lite::Captures| {
+                    let word = &caps[3];
+                    let formatted = match &caps[2] {
+                        "p" | "c" => format!("`{word}`"),
+                        "b" => format!("**{word}**"),
+                        _ => format!("*{word}*"),
+                    };
+                    format!("{}{formatted}", &caps[1])
+                }),
+            );
+        }
+    }
+    res
+}
+
+fn is_url(target: &str) -> bool {
+    target.starts_with("http://") || target.starts_with("https://")
+}
+
+/// Splits a `@see` reference into its target and description. The target
+/// ends at the first whitespace outside of parentheses, so that functions
+/// can be referred to by their full signature, like `Foo::bar(int, float)`
+fn split_see(see: &str) -> (&str, Option<&str>) {
+    let see = see.trim();
+    let mut depth = 0;
+    for (i, c) in see.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c.is_whitespace() && depth <= 0 => {
+                let rest = see[i..].trim();
+                return (&see[..i], (!rest.is_empty()).then_some(rest));
+            }
+            _ => {}
+        }
+    }
+    (see, None)
+}
+
+/// Checks if a doc comment hides its entity from the docs with `@internal`,
+/// `@private` or `@hidden`
+pub fn is_hidden_comment(raw: &str) -> bool {
+    let mut lexer = CommentLexer::new(raw);
+    while let Some(cmd) = lexer.next_command() {
+        if matches!(cmd.cmd.as_str(), "internal" | "private" | "hidden") {
+            return true;
+        }
+        lexer.next_value();
+    }
+    false
+}
+
+static GROUP_NAME: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[@\\]name\b").unwrap());
+static GROUP_OPEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[@\\]\{").unwrap());
+
+/// Takes the `@name` and `@{` opening a member group out of a doc comment,
+/// since LibClang attaches that comment to the first member in the group.
+/// Anything after the `@{` still documents the member itself
+pub fn strip_group_opening(raw: String) -> Option<String> {
+    let Some(name) = GROUP_NAME.find(&raw) else {
+        return Some(raw);
+    };
+    let rest = GROUP_OPEN
+        .find_at(&raw, name.end())
+        .map(|open| &raw[open.end()..])
+        .unwrap_or_default();
+    let has_text = rest.lines().any(|line| {
+        !line
+            .trim()
+            .trim_matches(['*', '/', '!', '<'])
+            .trim()
+            .is_empty()
+    });
+    if !has_text {
+        None
+    } else if raw.starts_with("/*") {
+        Some(format!("/**{rest}"))
+    } else {
+        Some(rest.trim_start().to_owned())
+    }
+}
+
+/// Finds the member group a doc comment puts its entity in with `@group`
+pub fn get_comment_group(raw: &str) -> Option<String> {
+    let mut lexer = CommentLexer::new(raw);
+    while let Some(cmd) = lexer.next_command() {
+        let value = lexer.next_value();
+        if cmd.cmd == "group" {
+            // The group name is only the rest of the line
+            return value.and_then(|v| v.lines().next().map(|l| l.trim().to_owned()));
+        }
+    }
+    None
+}
+
+/// Why an entity was deprecated, from either a `[[deprecated]]` attribute or
+/// a `@deprecated` command in its doc comment
+#[derive(Clone)]
+pub struct Deprecation {
+    /// Message explaining the deprecation
+    pub message: Option<String>,
+    /// What should be used instead, like `@deprecated[replacement = Name]`
+    pub replacement: Option<String>,
+}
+
+impl Deprecation {
+    /// Finds the `@deprecated` command in a doc comment
+    pub fn from_comment(raw: &str) -> Option<Self> {
+        let mut lexer = CommentLexer::new(raw);
+        while let Some(cmd) = lexer.next_command() {
+            if cmd.cmd == "deprecated" {
+                return Some(Self {
+                    message: lexer.next_value(),
+                    replacement: cmd.attrs.get("replacement").cloned().flatten(),
+                });
+            }
+            lexer.next_value();
+        }
+        None
+    }
+}
+
 pub struct JSDocComment<'e> {
     /// Description (duh)
     description: Option<String>,
     /// Parameters; specified with @param or @arg
     params: Vec<(String, String)>,
+    /// Whether parameters are inputs, outputs or both; specified Doxygen-style
+    /// with @param[in], @param[out] or @param[in,out]
+    param_directions: HashMap<String, String>,
     /// Template parameters; specified with @tparam
     tparams: Vec<(String, String)>,
     /// Return value
     returns: Option<String>,
+    /// Specific return values; specified with @retval
+    retvals: Vec<(String, String)>,
     /// What this throws
     throws: Option<String>,
     /// Refer to other doc item(s)
@@ -522,12 +852,26 @@ pub struct JSDocComment<'e> {
     short_notes: Vec<String>,
     /// Warnings about this item
     warnings: Vec<String>,
+    /// What must hold before using this item; specified with @pre
+    preconditions: Vec<String>,
+    /// What holds after using this item; specified with @post
+    postconditions: Vec<String>,
+    /// Work left to do on this item; specified with @todo
+    todos: Vec<String>,
     /// Item version
     version: Option<String>,
     /// When the item was added
     since: Option<String>,
     /// Examples
     examples: Vec<Example<'e>>,
+    /// Whether the docs of the overridden method should be merged into this
+    /// one; specified with @inheritdoc
+    inherit: bool,
+    /// Name of and link to the method these docs were inherited from
+    inherited_from: Option<(String, Option<String>)>,
+    /// Types of the (template) parameters in the signature, along with links
+    /// to their docs
+    param_types: HashMap<String, (String, Option<String>)>,
     /// Reference to builder
     builder: &'e Builder<'e>,
 }
@@ -538,22 +882,42 @@ impl<'e> JSDocComment<'e> {
 
         while let Some(cmd) = lexer.next_command() {
             match cmd.cmd.as_str() {
-                "description" | "desc" | "brief" =>
-                // Empty descriptions shouldn't result in warnings
-                // This does make it so empty @description doesn't warn but eh
-                // good enough
-                {
-                    self.description = lexer.next_value()
+                "description" | "desc" | "brief" | "short" | "details" => {
+                    // Empty descriptions shouldn't result in warnings
+                    // This does make it so empty @description doesn't warn but eh
+                    // good enough
+                    // Doxygen comments may have a brief and a detailed
+                    // description, so keep both
+                    if let Some(value) = lexer.next_value() {
+                        self.description = Some(match self.description.take() {
+                            Some(desc) => format!("{desc}\n\n{value}"),
+                            None => value,
+                        });
+                    }
+                }
+                "param" | "arg" => {
+                    let name = lexer.param_for(&cmd);
+                    let direction =
+                        match (cmd.attrs.contains_key("in"), cmd.attrs.contains_key("out")) {
+                            (true, true) => Some("in, out"),
+                            (true, false) => Some("in"),
+                            (false, true) => Some("out"),
+                            (false, false) => None,
+                        };
+                    if let Some(direction) = direction {
+                        self.param_directions.insert(name.clone(), direction.into());
+                    }
+                    self.params.push((name, lexer.value_for(&cmd)));
                 }
-                "param" | "arg" => self
-                    .params
-                    .push((lexer.param_for(&cmd), lexer.value_for(&cmd))),
                 "tparam" | "targ" => self
                     .tparams
                     .push((lexer.param_for(&cmd), lexer.value_for(&cmd))),
-                "return" | "returns" => self.returns = lexer.value_for(&cmd).into(),
-                "throws" => self.throws = lexer.value_for(&cmd).into(),
-                "see" => self.see.push(lexer.value_for(&cmd)),
+                "return" | "returns" | "result" => self.returns = lexer.value_for(&cmd).into(),
+                "retval" => self
+                    .retvals
+                    .push((lexer.param_for(&cmd), lexer.value_for(&cmd))),
+                "throws" | "throw" | "exception" => self.throws = lexer.value_for(&cmd).into(),
+                "see" | "sa" => self.see.push(lexer.value_for(&cmd)),
                 "note" => {
                     if cmd.attrs.contains_key("short") {
                         self.short_notes.push(lexer.value_for(&cmd))
@@ -561,14 +925,66 @@ impl<'e> JSDocComment<'e> {
                         self.notes.push(lexer.value_for(&cmd))
                     }
                 }
+                "remark" | "remarks" => self.notes.push(lexer.value_for(&cmd)),
                 "warning" | "warn" => self.warnings.push(lexer.value_for(&cmd)),
+                "pre" => self.preconditions.push(lexer.value_for(&cmd)),
+                "post" => self.postconditions.push(lexer.value_for(&cmd)),
+                "todo" => self.todos.push(lexer.value_for(&cmd)),
                 "version" => self.version = lexer.value_for(&cmd).into(),
                 "since" => self.since = lexer.value_for(&cmd).into(),
+                // Doxygen code blocks go on until @endcode
+                "code" if lexer.has_code_end() => self.examples.push(Example::new(
+                    lexer.next_code_block().unwrap_or_default(),
+                    cmd.attrs.contains_key("flash"),
+                    cmd.attrs.get("lang").cloned().flatten(),
+                    self.builder,
+                )),
                 "example" | "code" => self.examples.push(Example::new(
                     lexer.value_for(&cmd),
                     cmd.attrs.contains_key("flash"),
+                    cmd.attrs.get("lang").cloned().flatten(),
                     self.builder,
                 )),
+                "inheritdoc" | "inheritDoc" => self.inherit = true,
+                // these take no value, see is_hidden_comment
+                "internal" | "private" | "hidden" => {}
+                // Doxygen member group braces and stray code block ends
+                "{" | "}" | "endcode" => {}
+                // The group name only takes the rest of the line, see
+                // get_comment_group. Doxygen's structural commands and
+                // paragraph titles aren't shown either
+                "group" | "file" | "class" | "ingroup" | "par" => {
+                    if let Some((_, rest)) = lexer
+                        .next_value()
+                        .as_deref()
+                        .and_then(|v| v.split_once('\n'))
+                    {
+                        self.append_description(rest.trim(), "\n\n");
+                    }
+                }
+                // Doxygen list items, which become a markdown list
+                "li" => {
+                    if let Some(item) = lexer.next_value() {
+                        let is_list = self
+                            .description
+                            .as_ref()
+                            .and_then(|desc| desc.lines().last())
+                            .is_some_and(|line| line.starts_with("- "));
+                        self.append_description(
+                            &format!("- {}", item.trim()),
+                            if is_list { "\n" } else { "\n\n" },
+                        );
+                    }
+                }
+                // Doxygen member group names, which are shown on the group
+                // instead, see get_member_groups
+                "name" => {
+                    lexer.next_value();
+                }
+                // shown as a banner by the page instead, see Deprecation
+                "deprecated" => {
+                    lexer.next_value();
+                }
                 // _ => println!("Warning parsing JSDoc comment: Unknown command {cmd}"),
                 _ => {
                     // eat a value even though this is an unknown command
@@ -580,20 +996,38 @@ impl<'e> JSDocComment<'e> {
         self
     }
 
+    fn append_description(&mut self, text: &str, separator: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.description = Some(match self.description.take() {
+            Some(desc) => format!("{desc}{separator}{text}"),
+            None => text.to_owned(),
+        });
+    }
+
     pub fn new(builder: &'e Builder<'e>) -> Self {
         Self {
             description: None,
             params: Vec::new(),
+            param_directions: HashMap::new(),
             tparams: Vec::new(),
             returns: None,
+            retvals: Vec::new(),
             throws: None,
             see: Vec::new(),
             notes: Vec::new(),
             short_notes: Vec::new(),
             warnings: Vec::new(),
+            preconditions: Vec::new(),
+            postconditions: Vec::new(),
+            todos: Vec::new(),
             version: None,
             since: None,
             examples: Vec::new(),
+            inherit: false,
+            inherited_from: None,
+            param_types: HashMap::new(),
             builder,
         }
     }
@@ -602,9 +1036,260 @@ impl<'e> JSDocComment<'e> {
         Self::new(builder).parse_mut(raw)
     }
 
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    /// The first paragraph of the description, for places with no room for
+    /// the full docs
+    pub fn summary(&self) -> Option<String> {
+        self.description
+            .as_ref()
+            .and_then(|desc| desc.trim().split("\n\n").next())
+            .map(|par| par.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|par| !par.is_empty())
+    }
+
+    pub fn has_params(&self) -> bool {
+        !self.params.is_empty()
+    }
+
+    pub fn documents_param(&self, name: &str) -> bool {
+        self.params.iter().any(|p| p.0 == name)
+    }
+
+    pub fn documents_return(&self) -> bool {
+        self.returns.is_some() || !self.retvals.is_empty()
+    }
+
+    /// Whether this comment asks for the docs of the overridden method with
+    /// @inheritdoc
+    pub fn inherits(&self) -> bool {
+        self.inherit
+    }
+
+    /// Fills in everything this comment doesn't document from the comment of
+    /// the method it overrides
+    pub fn inherit_from(mut self, base: Self, name: String, url: Option<String>) -> Self {
+        self.description = self.description.or(base.description);
+        for param in base.params {
+            if !self.params.iter().any(|p| p.0 == param.0) {
+                self.params.push(param);
+            }
+        }
+        for tparam in base.tparams {
+            if !self.tparams.iter().any(|p| p.0 == tparam.0) {
+                self.tparams.push(tparam);
+            }
+        }
+        for (name, ty) in base.param_types {
+            self.param_types.entry(name).or_insert(ty);
+        }
+        for (name, direction) in base.param_directions {
+            self.param_directions.entry(name).or_insert(direction);
+        }
+        self.returns = self.returns.or(base.returns);
+        if self.retvals.is_empty() {
+            self.retvals = base.retvals;
+        }
+        self.throws = self.throws.or(base.throws);
+        if self.see.is_empty() {
+            self.see = base.see;
+        }
+        if self.notes.is_empty() {
+            self.notes = base.notes;
+        }
+        if self.warnings.is_empty() {
+            self.warnings = base.warnings;
+        }
+        if self.preconditions.is_empty() {
+            self.preconditions = base.preconditions;
+        }
+        if self.postconditions.is_empty() {
+            self.postconditions = base.postconditions;
+        }
+        if self.examples.is_empty() {
+            self.examples = base.examples;
+        }
+        // Point to where the docs were originally written
+        self.inherited_from = base.inherited_from.or(Some((name, url)));
+        self
+    }
+
+    /// Matches the documented (template) parameters against the signature of
+    /// the entity, putting them in the same order as in the signature
+    pub fn match_signature(self, entity: &Entity) -> Self {
+        self.apply_signature(entity, false)
+    }
+
+    /// Same as match_signature, but also warns about stale and missing
+    /// parameter docs. This is done once per entity when setting up the
+    /// build, see check_doc_comments
+    pub fn check_signature(self, entity: &Entity) -> Self {
+        self.apply_signature(entity, true)
+    }
+
+    fn apply_signature(mut self, entity: &Entity, warn: bool) -> Self {
+        let name = entity.full_name().join("::");
+        if let Some(args) = entity.get_function_arguments() {
+            // Parameters can also be documented with trailing comments in
+            // the signature, like `int x ///< The x position`
+            for arg in &args {
+                if let Some(name) = arg.get_name()
+                    && !self.documents_param(&name)
+                    && let Some(desc) = arg
+                        .get_doc_comment()
+                        .and_then(|raw| Self::parse(raw, self.builder).description)
+                {
+                    self.params.push((name, desc));
+                }
+            }
+            let signature = args
+                .iter()
+                .filter_map(|arg| Some((arg.get_name()?, arg.get_type())))
+                .collect::<Vec<_>>();
+            let params = std::mem::take(&mut self.params);
+            self.params = self.check_params("parameter", &name, params, signature, warn);
+        }
+        let signature = entity
+            .get_children()
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e.get_kind(),
+                    EntityKind::TemplateTypeParameter
+                        | EntityKind::NonTypeTemplateParameter
+                        | EntityKind::TemplateTemplateParameter
+                )
+            })
+            .filter_map(|param| {
+                let ty = (param.get_kind() == EntityKind::NonTypeTemplateParameter)
+                    .then(|| param.get_type())
+                    .flatten();
+                Some((param.get_name()?, ty))
+            })
+            .collect::<Vec<_>>();
+        let tparams = std::mem::take(&mut self.tparams);
+        self.tparams = self.check_params("template parameter", &name, tparams, signature, warn);
+        self
+    }
+
+    fn check_params(
+        &mut self,
+        kind: &str,
+        entity: &str,
+        mut documented: Vec<(String, String)>,
+        signature: Vec<(String, Option<Type>)>,
+        warn: bool,
+    ) -> Vec<(String, String)> {
+        for (name, _) in &documented {
+            if warn && !signature.iter().any(|p| p.0 == *name) {
+                warn!("Documented {kind} {name} is not in the signature of {entity}");
+            }
+        }
+        // Only warn about missing docs if the parameters are documented at all
+        let any_documented = !documented.is_empty();
+        let mut res = Vec::new();
+        for (name, ty) in signature {
+            match documented.iter().position(|p| p.0 == name) {
+                Some(ix) => res.push(documented.remove(ix)),
+                None if warn && any_documented => {
+                    warn!("The {kind} {name} of {entity} is not documented")
+                }
+                None => {}
+            }
+            if let Some(ty) = ty {
+                let url = ty
+                    .get_pointee_type()
+                    .unwrap_or(ty)
+                    .get_declaration()
+                    .and_then(|decl| decl.abs_docs_url(self.builder.config.clone()))
+                    .map(|url| url.to_string());
+                self.param_types.insert(name, (ty.get_display_name(), url));
+            }
+        }
+        // Keep stale docs at the end instead of silently dropping them
+        res.extend(documented);
+        res
+    }
+
+    /// Formats the text of any section of the comment as markdown, with
+    /// links to entities
+    fn fmt_text(&self, text: &str) -> Html {
+        fmt_markdown(
+            self.builder,
+            &fmt_autolinks(self.builder, &fmt_inline_commands(text)),
+            None::<fn(_) -> _>,
+        )
+    }
+
+    /// Same as fmt_text, but for short text inside other elements
+    fn fmt_inline_text(&self, text: &str) -> Html {
+        fmt_markdown_inline(
+            self.builder,
+            &fmt_autolinks(self.builder, &fmt_inline_commands(text)),
+        )
+    }
+
+    fn fmt_tag(&self, text: &str) -> Html {
+        HtmlElement::new("p")
+            .with_child(self.fmt_inline_text(text))
+            .into()
+    }
+
+    fn fmt_param_name(&self, name: &str) -> Html {
+        HtmlElement::new("p")
+            .with_child::<Html>(match self.param_types.get(name) {
+                Some((ty, url)) => HtmlElement::new("a")
+                    .with_attr("title", ty)
+                    .with_attr_opt("href", url.clone())
+                    .with_text(name)
+                    .into(),
+                None => HtmlText::new(name).into(),
+            })
+            .with_child_opt(
+                self.param_directions
+                    .get(name)
+                    .map(|direction| Html::span(&["direction", "space-before"], direction)),
+            )
+            .into()
+    }
+
+    fn fmt_list_section(&self, classes: &[&str], title: &str, items: &[String]) -> Option<Html> {
+        (!items.is_empty()).then(|| {
+            HtmlElement::new("section")
+                .with_class("params")
+                .with_classes(classes)
+                .with_child(Html::span(&["title"], title))
+                .with_child(
+                    HtmlElement::new("ul").with_children(
+                        items
+                            .iter()
+                            .map(|item| {
+                                HtmlElement::new("li")
+                                    .with_child(self.fmt_text(item))
+                                    .into()
+                            })
+                            .collect(),
+                    ),
+                )
+                .into()
+        })
+    }
+
     pub fn to_html(&self, include_examples: bool) -> Html {
         HtmlList::new(vec![HtmlElement::new("div")
             .with_class("description")
+            .with_child_opt(self.inherited_from.as_ref().map(|(name, url)| {
+                HtmlElement::new("p")
+                    .with_class("inherited-from")
+                    .with_text("Inherited from ")
+                    .with_child(
+                        HtmlElement::new("a")
+                            .with_attr_opt("href", url.clone())
+                            .with_child(HtmlElement::new("code").with_text(name)),
+                    )
+            }))
             .with_child_opt(
                 if self.version.is_some() || self.since.is_some() || !self.short_notes.is_empty() {
                     HtmlElement::new("div")
@@ -612,10 +1297,19 @@ impl<'e> JSDocComment<'e> {
                         .with_child_opt(
                             self.version
                                 .as_ref()
-                                .map(|v| Html::p(format!("Version {v}"))),
+                                .map(|v| self.fmt_tag(&format!("Version {v}"))),
+                        )
+                        .with_child_opt(
+                            self.since
+                                .as_ref()
+                                .map(|v| self.fmt_tag(&format!("Since {v}"))),
+                        )
+                        .with_children(
+                            self.short_notes
+                                .iter()
+                                .map(|note| self.fmt_tag(note))
+                                .collect(),
                         )
-                        .with_child_opt(self.since.as_ref().map(|v| Html::p(format!("Since {v}"))))
-                        .with_children(self.short_notes.iter().map(Html::p).collect())
                         .into()
                 } else {
                     None
@@ -624,13 +1318,7 @@ impl<'e> JSDocComment<'e> {
             .with_child(
                 self.description
                     .as_ref()
-                    .map(|d| {
-                        fmt_markdown(
-                            self.builder,
-                            &fmt_autolinks(self.builder, d),
-                            None::<fn(_) -> _>,
-                        )
-                    })
+                    .map(|d| self.fmt_text(d))
                     .unwrap_or(Html::span(&["no-desc"], "No description provided")),
             )
             .with_child_opt(
@@ -643,7 +1331,7 @@ impl<'e> JSDocComment<'e> {
                                 self.params
                                     .iter()
                                     .flat_map(|param| {
-                                        vec![Html::p(param.0.clone()), Html::div(param.1.clone())]
+                                        vec![self.fmt_param_name(&param.0), self.fmt_text(&param.1)]
                                     })
                                     .collect(),
                             ),
@@ -660,33 +1348,70 @@ impl<'e> JSDocComment<'e> {
                                 self.tparams
                                     .iter()
                                     .flat_map(|tparam| {
-                                        vec![Html::p(tparam.0.clone()), Html::div(tparam.1.clone())]
+                                        vec![
+                                            self.fmt_param_name(&tparam.0),
+                                            self.fmt_text(&tparam.1),
+                                        ]
                                     })
                                     .collect(),
                             ),
                         ),
                 ),
             )
-            .with_child_opt(self.returns.as_ref().map(|ret| {
-                HtmlElement::new("section")
-                    .with_classes(&["params", "returns", "grid"])
-                    .with_child(Html::span(&["title"], "Return value"))
-                    .with_child(Html::div(ret.clone()))
-            }))
+            .with_child_opt(
+                (self.returns.is_some() || !self.retvals.is_empty()).then(|| {
+                    HtmlElement::new("section")
+                        .with_classes(&["params", "returns", "grid"])
+                        .with_child(Html::span(&["title"], "Return value"))
+                        .with_child_opt(self.returns.as_ref().map(|ret| self.fmt_text(ret)))
+                        .with_child_opt((!self.retvals.is_empty()).then(|| {
+                            HtmlElement::new("div").with_class("grid").with_children(
+                                self.retvals
+                                    .iter()
+                                    .flat_map(|(value, desc)| {
+                                        vec![
+                                            HtmlElement::new("p")
+                                                .with_child(
+                                                    HtmlElement::new("code").with_text(value),
+                                                )
+                                                .into(),
+                                            self.fmt_text(desc),
+                                        ]
+                                    })
+                                    .collect(),
+                            )
+                        }))
+                }),
+            )
             .with_child_opt(self.throws.as_ref().map(|ret| {
                 HtmlElement::new("section")
                     .with_classes(&["params", "throws", "grid"])
                     .with_child(Html::span(&["title"], "Exceptions"))
-                    .with_child(Html::div(ret.clone()))
+                    .with_child(self.fmt_text(ret))
             }))
-            // todo: see
+            .with_child_opt((!self.see.is_empty()).then(|| {
+                HtmlElement::new("section")
+                    .with_classes(&["params", "see"])
+                    .with_child(Html::span(&["title"], "See also"))
+                    .with_child(
+                        HtmlElement::new("ul")
+                            .with_children(self.see.iter().map(|see| self.fmt_see(see)).collect()),
+                    )
+            }))
+            .with_child_opt(self.fmt_list_section(&["pre"], "Preconditions", &self.preconditions))
+            .with_child_opt(self.fmt_list_section(
+                &["post"],
+                "Postconditions",
+                &self.postconditions,
+            ))
+            .with_child_opt(self.fmt_list_section(&["todo"], "To do", &self.todos))
             .with_children(
                 self.notes
                     .iter()
                     .map(|note| {
                         HtmlElement::new("blockquote")
                             .with_class("info")
-                            .with_child(Html::p(format!("ℹ {}", note)))
+                            .with_child(self.fmt_text(&format!("ℹ {}", note)))
                             .into()
                     })
                     .collect(),
@@ -697,7 +1422,7 @@ impl<'e> JSDocComment<'e> {
                     .map(|warning| {
                         HtmlElement::new("blockquote")
                             .with_class("warning")
-                            .with_child(Html::p(format!("⚠️ {}", warning)))
+                            .with_child(self.fmt_text(&format!("⚠️ {}", warning)))
                             .into()
                     })
                     .collect(),
@@ -714,7 +1439,192 @@ impl<'e> JSDocComment<'e> {
         .into()
     }
 
+    /// Gets every piece of text in the comment that's rendered as markdown
+    fn texts(&self) -> impl Iterator<Item = &String> {
+        self.description
+            .iter()
+            .chain(self.params.iter().map(|(_, text)| text))
+            .chain(self.tparams.iter().map(|(_, text)| text))
+            .chain(&self.returns)
+            .chain(self.retvals.iter().map(|(_, text)| text))
+            .chain(&self.throws)
+            .chain(&self.notes)
+            .chain(&self.short_notes)
+            .chain(&self.warnings)
+            .chain(&self.preconditions)
+            .chain(&self.postconditions)
+            .chain(&self.todos)
+            .chain(&self.version)
+            .chain(&self.since)
+    }
+
+    /// Gets the `@see`, `{@link}` and `@ref` references that don't resolve
+    /// to anything, for warning about them once per build, see
+    /// check_doc_comments
+    pub fn unresolved_references(&self) -> Vec<String> {
+        let mut unresolved = self
+            .see
+            .iter()
+            .map(|see| split_see(see))
+            .filter(|(target, _)| {
+                !is_url(target)
+                    && resolve_name(self.builder, target).is_none()
+                    && !names_namespace(self.builder, target)
+            })
+            .map(|(target, _)| target.to_owned())
+            .collect::<Vec<_>>();
+        for text in self
+            .texts()
+            .map(|text| text.as_str())
+            .chain(self.see.iter().filter_map(|see| split_see(see).1))
+        {
+            unresolved.extend(unresolved_links(self.builder, &fmt_inline_commands(text)));
+        }
+        unresolved
+    }
+
+    /// Formats a `@see` reference, which is either a URL or the name of an
+    /// entity, optionally followed by a description. Namespaces have no page
+    /// to link to, so they're shown without a link
+    fn fmt_see(&self, see: &str) -> Html {
+        let (target, rest) = split_see(see);
+        let link = if is_url(target) {
+            Some(target.to_owned())
+        } else {
+            resolve_name(self.builder, target)
+        };
+
+        HtmlElement::new("li")
+            .with_child(
+                HtmlElement::new("a")
+                    .with_attr_opt("href", link)
+                    .with_child(HtmlElement::new("code").with_text(target)),
+            )
+            .with_child_opt(
+                rest.map(|r| {
+                    HtmlList::new(vec![HtmlText::new(" ").into(), self.fmt_inline_text(r)])
+                }),
+            )
+            .into()
+    }
+
     pub fn examples(&self) -> &Vec<Example> {
         &self.examples
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lexes a comment the way JSDocComment does, into (command, param, value)
+    fn lex(raw: &str) -> Vec<(String, Option<String>, Option<String>)> {
+        let mut lexer = CommentLexer::new(raw);
+        let mut res = Vec::new();
+        while let Some(cmd) = lexer.next_command() {
+            let (param, value) = match cmd.cmd.as_str() {
+                "param" | "tparam" | "retval" => (lexer.next_param(), lexer.next_value()),
+                "code" if lexer.has_code_end() => (
+                    cmd.attrs.get("lang").cloned().flatten(),
+                    lexer.next_code_block(),
+                ),
+                _ => (None, lexer.next_value()),
+            };
+            res.push((cmd.cmd, param, value));
+        }
+        res
+    }
+
+    fn cmd(
+        cmd: &str,
+        param: Option<&str>,
+        value: Option<&str>,
+    ) -> (String, Option<String>, Option<String>) {
+        (cmd.into(), param.map(Into::into), value.map(Into::into))
+    }
+
+    #[test]
+    fn doxygen_backslash_commands() {
+        assert_eq!(
+            lex("/**\n * Does things\n * \\param x The x\n * \\return Nothing\n */"),
+            vec![
+                cmd("description", None, Some("Does things")),
+                cmd("param", Some("x"), Some("The x")),
+                cmd("return", None, Some("Nothing")),
+            ]
+        );
+    }
+
+    #[test]
+    fn backslashes_in_text() {
+        assert_eq!(
+            lex("/// Prints a \\n at the end\n/// \\brief Prints"),
+            vec![
+                cmd("description", None, Some("Prints a \\n at the end")),
+                cmd("brief", None, Some("Prints")),
+            ]
+        );
+    }
+
+    #[test]
+    fn qt_style_comments() {
+        assert_eq!(
+            lex("/*! Does things\n    @see Foo */"),
+            vec![
+                cmd("description", None, Some("Does things")),
+                cmd("see", None, Some("Foo")),
+            ]
+        );
+        assert_eq!(
+            lex("//! Does things\n//! @note Carefully"),
+            vec![
+                cmd("description", None, Some("Does things")),
+                cmd("note", None, Some("Carefully")),
+            ]
+        );
+    }
+
+    #[test]
+    fn inline_commands_are_text() {
+        assert_eq!(
+            lex("/// See @ref Foo and {@link Bar}"),
+            vec![cmd(
+                "description",
+                None,
+                Some("See @ref Foo and {@link Bar}")
+            )]
+        );
+    }
+
+    #[test]
+    fn code_block_language() {
+        assert_eq!(
+            lex("/**\n * @code{.py}\n * print(\"@hi\")\n * @endcode\n * @code\n * int x;\n * \\endcode\n */"),
+            vec![
+                cmd("code", Some("py"), Some("print(\"@hi\")")),
+                cmd("code", None, Some("int x;")),
+            ]
+        );
+    }
+
+    #[test]
+    fn see_targets() {
+        assert_eq!(split_see("Foo::bar"), ("Foo::bar", None));
+        assert_eq!(
+            split_see(" Foo::bar  Does things "),
+            ("Foo::bar", Some("Does things"))
+        );
+        assert_eq!(
+            split_see("Foo::bar(int, float) Does things"),
+            ("Foo::bar(int, float)", Some("Does things"))
+        );
+        assert_eq!(split_see("operator()(int)"), ("operator()(int)", None));
+    }
+
+    #[test]
+    fn hidden_comments() {
+        assert!(is_hidden_comment("/// Does things\n/// @internal"));
+        assert!(is_hidden_comment("/** \\private */"));
+        assert!(!is_hidden_comment("/// Does things with @ref Foo"));
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use crate::{html::Html, url::UrlPath};
+use clang::Entity;
+
+use super::{
+    builder::Builder,
+    shared::output_concept,
+    traits::{ASTEntry, BuildResult, EntityMethods, Entry, NavItem, OutputEntry},
+};
+
+pub struct Concept<'e> {
+    entity: Entity<'e>,
+}
+
+impl<'e> Concept<'e> {
+    pub fn new(entity: Entity<'e>) -> Self {
+        Self { entity }
+    }
+}
+
+impl<'e> Entry<'e> for Concept<'e> {
+    fn name(&self) -> String {
+        self.entity
+            .get_name()
+            .unwrap_or("`Anonymous concept`".into())
+    }
+
+    fn url(&self) -> UrlPath {
+        self.entity
+            .rel_docs_url()
+            .expect("Unable to get concept URL")
+    }
+
+    fn build(&self, builder: &Builder<'e>) -> BuildResult {
+        builder.create_output_for(self)
+    }
+
+    fn nav(&self) -> NavItem {
+        NavItem::new_link(
+            &self.name(),
+            self.url(),
+            Some(("check-circle", false)),
+            Vec::new(),
+        )
+        .deprecated(self.entity.get_deprecation().is_some())
+    }
+}
+
+impl<'e> ASTEntry<'e> for Concept<'e> {
+    fn entity(&self) -> &Entity<'e> {
+        &self.entity
+    }
+
+    fn category(&self) -> &'static str {
+        "concept"
+    }
+}
+
+impl<'e> OutputEntry<'e> for Concept<'e> {
+    fn output(&self, builder: &Builder<'e>) -> (Arc<String>, Vec<(&'static str, Html)>) {
+        (
+            builder.config.templates.concept.clone(),
+            output_concept(self, builder),
+        )
+    }
+
+    fn description(&self, builder: &'e Builder<'e>) -> String {
+        self.output_description(builder)
+    }
+}
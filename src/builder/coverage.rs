@@ -0,0 +1,402 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use clang::{Entity, EntityKind, TypeKind};
+use serde_json::{json, Value};
+
+use crate::{
+    html::{Html, HtmlElement, HtmlText},
+    url::UrlPath,
+};
+
+use super::{
+    builder::Builder,
+    shared::{fmt_section, get_linkable, get_method_doc_comment},
+    traits::{BuildResult, EntityMethods, Entry, NavItem, OutputEntry},
+};
+
+/// How many of some kind of thing are documented
+#[derive(Default, Clone, Copy)]
+pub struct Counts {
+    pub documented: usize,
+    pub total: usize,
+}
+
+impl Counts {
+    fn add(&mut self, documented: bool) {
+        self.total += 1;
+        if documented {
+            self.documented += 1;
+        }
+    }
+
+    fn extend(&mut self, other: Counts) {
+        self.documented += other.documented;
+        self.total += other.total;
+    }
+
+    /// Percentage of documented things, which is 100% if there's nothing to
+    /// document
+    pub fn percentage(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.documented as f64 * 100.0 / self.total as f64
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "documented": self.documented,
+            "total": self.total,
+            "percentage": self.percentage(),
+        })
+    }
+
+    fn to_html(self) -> Html {
+        HtmlElement::new("td")
+            .with_class_opt((self.documented < self.total).then_some("missing"))
+            .with_text(format!(
+                "{:.1}% ({}/{})",
+                self.percentage(),
+                self.documented,
+                self.total
+            ))
+            .into()
+    }
+}
+
+/// Documentation coverage of a group of entities
+#[derive(Default, Clone, Copy)]
+pub struct Stats {
+    /// Every entity, including functions
+    pub entities: Counts,
+    /// Functions and methods
+    pub methods: Counts,
+    /// Named parameters of functions and methods
+    pub params: Counts,
+    /// Return values of functions and methods that don't return void
+    pub returns: Counts,
+}
+
+impl Stats {
+    fn extend(&mut self, other: &Stats) {
+        self.entities.extend(other.entities);
+        self.methods.extend(other.methods);
+        self.params.extend(other.params);
+        self.returns.extend(other.returns);
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "entities": self.entities.to_json(),
+            "methods": self.methods.to_json(),
+            "parameters": self.params.to_json(),
+            "return_values": self.returns.to_json(),
+        })
+    }
+}
+
+/// An entity that is missing some of its documentation
+pub struct Undocumented {
+    pub name: String,
+    pub url: Option<String>,
+    pub file: Option<String>,
+    /// What is missing, like `description` or `parameter x`
+    pub missing: Vec<String>,
+}
+
+/// Documentation coverage of the whole project
+#[derive(Default)]
+pub struct Coverage {
+    pub total: Stats,
+    pub namespaces: BTreeMap<String, Stats>,
+    pub files: BTreeMap<String, Stats>,
+    pub classes: BTreeMap<String, Stats>,
+    pub undocumented: Vec<Undocumented>,
+}
+
+fn is_deleted_or_defaulted(entity: &Entity) -> bool {
+    matches!(
+        entity.get_kind(),
+        EntityKind::Method
+            | EntityKind::Constructor
+            | EntityKind::Destructor
+            | EntityKind::ConversionFunction
+    ) && (entity.is_defaulted() || entity.get_function_tail().deleted)
+}
+
+fn is_classlike(entity: &Entity) -> bool {
+    matches!(
+        entity.get_kind(),
+        EntityKind::ClassDecl
+            | EntityKind::StructDecl
+            | EntityKind::UnionDecl
+            | EntityKind::ClassTemplate
+            | EntityKind::ClassTemplatePartialSpecialization
+    )
+}
+
+impl Coverage {
+    pub fn new(builder: &Builder) -> Self {
+        let mut coverage = Self::default();
+        for (entity, url) in builder
+            .root
+            .all_entries()
+            .flat_map(|entry| get_linkable(entry, builder))
+        {
+            // Namespaces can't really be documented, and neither can
+            // special members that are just deleted or defaulted
+            if entity.get_kind() != EntityKind::Namespace && !is_deleted_or_defaulted(&entity) {
+                coverage.add(&entity, url, builder);
+            }
+        }
+        coverage.undocumented.sort_by(|a, b| a.name.cmp(&b.name));
+        coverage
+    }
+
+    fn add(&mut self, entity: &Entity, url: Option<String>, builder: &Builder) {
+        let comment = get_method_doc_comment(entity, builder);
+        let mut stats = Stats::default();
+        let mut missing = Vec::new();
+
+        let documented = comment.as_ref().is_some_and(|c| c.has_description());
+        stats.entities.add(documented);
+        if comment.is_some() && !documented {
+            missing.push("description".to_string());
+        }
+
+        let kind = entity.get_function_kind();
+        if matches!(
+            kind,
+            EntityKind::FunctionDecl
+                | EntityKind::Method
+                | EntityKind::Constructor
+                | EntityKind::Destructor
+                | EntityKind::ConversionFunction
+        ) {
+            stats.methods.add(documented);
+
+            for arg in entity.get_function_arguments().unwrap_or_default() {
+                let Some(name) = arg.get_name() else {
+                    continue;
+                };
+                // inline docs of parameters are already merged into the comment
+                let documented = comment.as_ref().is_some_and(|c| c.documents_param(&name));
+                stats.params.add(documented);
+                if comment.is_some() && !documented {
+                    missing.push(format!("parameter {name}"));
+                }
+            }
+
+            if !matches!(kind, EntityKind::Constructor | EntityKind::Destructor)
+                && entity
+                    .get_result_type()
+                    .is_some_and(|t| t.get_canonical_type().get_kind() != TypeKind::Void)
+            {
+                let documented = comment.as_ref().is_some_and(|c| c.documents_return());
+                stats.returns.add(documented);
+                if comment.is_some() && !documented {
+                    missing.push("return value".to_string());
+                }
+            }
+        }
+
+        // With no comment at all, listing every missing part is just noise
+        if comment.is_none() {
+            missing.push("documentation".to_string());
+        }
+
+        let name = entity.full_name().join("::");
+        let namespace = entity
+            .ancestorage()
+            .into_iter()
+            .rev()
+            .find(|e| e.get_kind() == EntityKind::Namespace)
+            .map(|ns| ns.full_name().join("::"))
+            .unwrap_or("(global)".into());
+        let file = entity
+            .header(builder.config.clone())
+            .map(|path| path.to_string_lossy().replace('\\', "/"));
+        let class = if is_classlike(entity) {
+            Some(name.clone())
+        } else {
+            entity
+                .get_semantic_parent()
+                .filter(is_classlike)
+                .map(|parent| parent.full_name().join("::"))
+        };
+
+        self.total.extend(&stats);
+        self.namespaces.entry(namespace).or_default().extend(&stats);
+        self.files
+            .entry(file.clone().unwrap_or("(unknown)".into()))
+            .or_default()
+            .extend(&stats);
+        if let Some(class) = class {
+            self.classes.entry(class).or_default().extend(&stats);
+        }
+        if !missing.is_empty() {
+            self.undocumented.push(Undocumented {
+                name,
+                url,
+                file,
+                missing,
+            });
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let groups = |groups: &BTreeMap<String, Stats>| {
+            groups
+                .iter()
+                .map(|(name, stats)| (name.clone(), stats.to_json()))
+                .collect::<serde_json::Map<_, _>>()
+        };
+        json!({
+            "total": self.total.to_json(),
+            "namespaces": groups(&self.namespaces),
+            "files": groups(&self.files),
+            "classes": groups(&self.classes),
+            "undocumented": self.undocumented.iter().map(|item| json!({
+                "name": item.name,
+                "url": item.url,
+                "file": item.file,
+                "missing": item.missing,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+fn fmt_coverage_row(name: Html, stats: &Stats) -> Html {
+    HtmlElement::new("tr")
+        .with_child(HtmlElement::new("td").with_child(name))
+        .with_child(stats.entities.to_html())
+        .with_child(stats.methods.to_html())
+        .with_child(stats.params.to_html())
+        .with_child(stats.returns.to_html())
+        .into()
+}
+
+fn fmt_coverage_table(title: &str, groups: &BTreeMap<String, Stats>) -> Html {
+    HtmlElement::new("details")
+        .with_attr("open", "")
+        .with_classes(&["section", "coverage"])
+        .with_child(
+            HtmlElement::new("summary").with_child(
+                HtmlElement::new("span")
+                    .with_child(Html::feather("chevron-right"))
+                    .with_child(HtmlText::new(title))
+                    .with_child(Html::span(&["badge"], &groups.len().to_string())),
+            ),
+        )
+        .with_child(
+            HtmlElement::new("div").with_child(
+                HtmlElement::new("table")
+                    .with_child(
+                        HtmlElement::new("tr")
+                            .with_child(HtmlElement::new("th").with_text("Name"))
+                            .with_child(HtmlElement::new("th").with_text("Entities"))
+                            .with_child(HtmlElement::new("th").with_text("Methods"))
+                            .with_child(HtmlElement::new("th").with_text("Parameters"))
+                            .with_child(HtmlElement::new("th").with_text("Return values")),
+                    )
+                    .with_children(
+                        groups
+                            .iter()
+                            .map(|(name, stats)| {
+                                fmt_coverage_row(Html::span(&["name"], name), stats)
+                            })
+                            .collect(),
+                    ),
+            ),
+        )
+        .into()
+}
+
+pub fn output_coverage(builder: &Builder) -> Vec<(&'static str, Html)> {
+    let coverage = builder.coverage();
+    vec![
+        ("name", HtmlText::new("Documentation coverage").into()),
+        (
+            "total",
+            fmt_coverage_table(
+                "Total",
+                &BTreeMap::from([(builder.config.project.name.clone(), coverage.total)]),
+            ),
+        ),
+        (
+            "namespaces",
+            fmt_coverage_table("Namespaces", &coverage.namespaces),
+        ),
+        ("files", fmt_coverage_table("Files", &coverage.files)),
+        ("classes", fmt_coverage_table("Classes", &coverage.classes)),
+        (
+            "undocumented",
+            fmt_section(
+                "Undocumented",
+                coverage
+                    .undocumented
+                    .iter()
+                    .map(|item| {
+                        HtmlElement::new("div")
+                            .with_class("undocumented-entry")
+                            .with_child(
+                                HtmlElement::new("a")
+                                    .with_attr_opt("href", item.url.clone())
+                                    .with_child(Html::span(&["name"], &item.name)),
+                            )
+                            .with_child_opt(
+                                item.file
+                                    .as_ref()
+                                    .map(|file| Html::span(&["file", "space-before"], file)),
+                            )
+                            .with_child(Html::p(format!("Missing {}", item.missing.join(", "))))
+                            .into()
+                    })
+                    .collect(),
+            ),
+        ),
+    ]
+}
+
+/// The page with the documentation coverage report
+pub struct CoverageReport;
+
+impl<'e> Entry<'e> for CoverageReport {
+    fn name(&self) -> String {
+        "Coverage".into()
+    }
+
+    fn url(&self) -> UrlPath {
+        UrlPath::parse("coverage").unwrap()
+    }
+
+    fn build(&self, builder: &Builder<'e>) -> BuildResult {
+        builder.create_output_for(self)
+    }
+
+    fn nav(&self) -> NavItem {
+        NavItem::new_link(
+            &self.name(),
+            self.url(),
+            Some(("pie-chart", false)),
+            Vec::new(),
+        )
+    }
+}
+
+impl<'e> OutputEntry<'e> for CoverageReport {
+    fn output(&self, builder: &'e Builder<'e>) -> (Arc<String>, Vec<(&'static str, Html)>) {
+        (
+            builder.config.templates.coverage.clone(),
+            output_coverage(builder),
+        )
+    }
+
+    fn description(&self, builder: &'e Builder<'e>) -> String {
+        format!(
+            "How much of {} has been documented",
+            builder.config.project.name
+        )
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::{html::Html, url::UrlPath};
+
+use super::{
+    builder::Builder,
+    shared::output_deprecated,
+    traits::{BuildResult, Entry, NavItem, OutputEntry},
+};
+
+/// The page listing everything that has been deprecated
+pub struct DeprecatedIndex;
+
+impl<'e> Entry<'e> for DeprecatedIndex {
+    fn name(&self) -> String {
+        "Deprecated API".into()
+    }
+
+    fn url(&self) -> UrlPath {
+        UrlPath::parse("deprecated").unwrap()
+    }
+
+    fn build(&self, builder: &Builder<'e>) -> BuildResult {
+        builder.create_output_for(self)
+    }
+
+    fn nav(&self) -> NavItem {
+        NavItem::new_link(
+            &self.name(),
+            self.url(),
+            Some(("alert-triangle", false)),
+            Vec::new(),
+        )
+    }
+}
+
+impl<'e> OutputEntry<'e> for DeprecatedIndex {
+    fn output(&self, builder: &'e Builder<'e>) -> (Arc<String>, Vec<(&'static str, Html)>) {
+        (
+            builder.config.templates.deprecated.clone(),
+            output_deprecated(builder),
+        )
+    }
+
+    fn description(&self, builder: &'e Builder<'e>) -> String {
+        format!(
+            "Everything that has been deprecated in {}",
+            builder.config.project.name
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::{html::Html, url::UrlPath};
+use clang::Entity;
+
+use super::{
+    builder::Builder,
+    shared::output_enum,
+    traits::{ASTEntry, BuildResult, EntityMethods, Entry, NavItem, OutputEntry},
+};
+
+pub struct Enum<'e> {
+    entity: Entity<'e>,
+}
+
+impl<'e> Enum<'e> {
+    pub fn new(entity: Entity<'e>) -> Self {
+        Self { entity }
+    }
+}
+
+impl<'e> Entry<'e> for Enum<'e> {
+    fn name(&self) -> String {
+        self.entity
+            .get_display_name()
+            .unwrap_or("`Anonymous enum`".into())
+    }
+
+    fn url(&self) -> UrlPath {
+        self.entity.rel_docs_url().expect("Unable to get enum URL")
+    }
+
+    fn build(&self, builder: &Builder<'e>) -> BuildResult {
+        builder.create_output_for(self)
+    }
+
+    fn nav(&self) -> NavItem {
+        NavItem::new_link(&self.name(), self.url(), Some(("list", false)), Vec::new())
+            .deprecated(self.entity.get_deprecation().is_some())
+    }
+}
+
+impl<'e> ASTEntry<'e> for Enum<'e> {
+    fn entity(&self) -> &Entity<'e> {
+        &self.entity
+    }
+
+    fn category(&self) -> &'static str {
+        "enum"
+    }
+}
+
+impl<'e> OutputEntry<'e> for Enum<'e> {
+    fn output(&self, builder: &Builder<'e>) -> (Arc<String>, Vec<(&'static str, Html)>) {
+        (
+            builder.config.templates.enum_.clone(),
+            output_enum(self, builder),
+        )
+    }
+
+    fn description(&self, builder: &'e Builder<'e>) -> String {
+        self.output_description(builder)
+    }
+}
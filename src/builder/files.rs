@@ -1,7 +1,10 @@
 use super::{
     builder::Builder,
     namespace::CppItemKind,
-    shared::{fmt_class_method, fmt_classlike_decl, fmt_section},
+    shared::{
+        fmt_alias, fmt_class_method, fmt_classlike_decl, fmt_concept, fmt_macro, fmt_section,
+        fmt_variable,
+    },
     traits::{ASTEntry, BuildResult, Entry, NavItem, OutputEntry},
 };
 use crate::{
@@ -9,6 +12,7 @@ use crate::{
     html::{Html, HtmlText},
     url::UrlPath,
 };
+use clang::Entity;
 use std::{collections::HashMap, path::Path, sync::Arc};
 
 pub struct File {
@@ -42,9 +46,8 @@ impl<'e> Entry<'e> for File {
 
 impl<'e> OutputEntry<'e> for File {
     fn output(&self, builder: &'e Builder<'e>) -> (Arc<String>, Vec<(&'static str, Html)>) {
-        let matcher = |entry: &dyn ASTEntry<'e>| -> bool {
-            entry
-                .entity()
+        let in_file = |entity: &Entity| -> bool {
+            entity
                 .get_location()
                 .and_then(|file| file.get_file_location().file)
                 .is_some_and(|file| {
@@ -55,6 +58,7 @@ impl<'e> OutputEntry<'e> for File {
                             .join(self.source.dir.join(&self.path).to_raw_string())
                 })
         };
+        let matcher = |entry: &dyn ASTEntry<'e>| -> bool { in_file(entry.entity()) };
 
         (
             builder.config.templates.file.clone(),
@@ -91,10 +95,46 @@ impl<'e> OutputEntry<'e> for File {
                                 matches!(
                                     CppItemKind::from(entry.entity()),
                                     Some(CppItemKind::Function)
+                                ) && entry.overloads().iter().any(in_file)
+                            })
+                            .into_iter()
+                            .flat_map(|fun| fun.overloads())
+                            .filter(in_file)
+                            .map(|fun| fmt_class_method(&fun, builder))
+                            .collect(),
+                    ),
+                ),
+                (
+                    "variables",
+                    fmt_section(
+                        "Variables",
+                        builder
+                            .root
+                            .get(&|entry| {
+                                matches!(
+                                    CppItemKind::from(entry.entity()),
+                                    Some(CppItemKind::Variable)
                                 ) && matcher(entry)
                             })
                             .into_iter()
-                            .map(|fun| fmt_class_method(fun.entity(), builder))
+                            .map(|var| fmt_variable(var.entity(), builder))
+                            .collect(),
+                    ),
+                ),
+                (
+                    "macros",
+                    fmt_section(
+                        "Macros",
+                        builder
+                            .root
+                            .get(&|entry| {
+                                matches!(
+                                    CppItemKind::from(entry.entity()),
+                                    Some(CppItemKind::Macro)
+                                ) && matcher(entry)
+                            })
+                            .into_iter()
+                            .map(|mac| fmt_macro(mac.entity(), builder))
                             .collect(),
                     ),
                 ),
@@ -132,6 +172,55 @@ impl<'e> OutputEntry<'e> for File {
                             .collect(),
                     ),
                 ),
+                (
+                    "enums",
+                    fmt_section(
+                        "Enums",
+                        builder
+                            .root
+                            .get(&|entry| {
+                                matches!(CppItemKind::from(entry.entity()), Some(CppItemKind::Enum))
+                                    && matcher(entry)
+                            })
+                            .into_iter()
+                            .map(|en| fmt_classlike_decl(en.entity(), "enum", builder))
+                            .collect(),
+                    ),
+                ),
+                (
+                    "aliases",
+                    fmt_section(
+                        "Type aliases",
+                        builder
+                            .root
+                            .get(&|entry| {
+                                matches!(
+                                    CppItemKind::from(entry.entity()),
+                                    Some(CppItemKind::Alias)
+                                ) && matcher(entry)
+                            })
+                            .into_iter()
+                            .map(|al| fmt_alias(al.entity(), builder))
+                            .collect(),
+                    ),
+                ),
+                (
+                    "concepts",
+                    fmt_section(
+                        "Concepts",
+                        builder
+                            .root
+                            .get(&|entry| {
+                                matches!(
+                                    CppItemKind::from(entry.entity()),
+                                    Some(CppItemKind::Concept)
+                                ) && matcher(entry)
+                            })
+                            .into_iter()
+                            .map(|con| fmt_concept(con.entity(), builder))
+                            .collect(),
+                    ),
+                ),
             ],
         )
     }
@@ -10,24 +10,48 @@ use super::{
 };
 
 pub struct Function<'e> {
-    entity: Entity<'e>,
+    overloads: Vec<Entity<'e>>,
 }
 
 impl<'e> Function<'e> {
     pub fn new(entity: Entity<'e>) -> Self {
-        Self { entity }
+        Self {
+            overloads: vec![entity],
+        }
+    }
+
+    /// Add another overload of this function. Redeclarations of an existing
+    /// overload are ignored, unless the new one is the one with documentation
+    pub fn add_overload(&mut self, entity: Entity<'e>) {
+        if let Some(existing) = self
+            .overloads
+            .iter_mut()
+            .find(|o| o.get_usr() == entity.get_usr())
+        {
+            if existing.get_doc_comment().is_none() {
+                *existing = entity;
+            }
+        } else {
+            self.overloads.push(entity);
+        }
+    }
+
+    pub fn merge_with_function(&mut self, other: Function<'e>) {
+        for overload in other.overloads {
+            self.add_overload(overload);
+        }
     }
 }
 
 impl<'e> Entry<'e> for Function<'e> {
     fn name(&self) -> String {
-        self.entity
+        self.entity()
             .get_name()
             .unwrap_or("`Anonymous function`".into())
     }
 
     fn url(&self) -> UrlPath {
-        self.entity
+        self.entity()
             .rel_docs_url()
             .expect("Unable to get function URL")
     }
@@ -37,18 +61,31 @@ impl<'e> Entry<'e> for Function<'e> {
     }
 
     fn nav(&self) -> NavItem {
-        NavItem::new_link(&self.name(), self.url(), Some(("code", true)), Vec::new())
+        let name = if self.overloads.len() > 1 {
+            format!("{} ({})", self.name(), self.overloads.len())
+        } else {
+            self.name()
+        };
+        NavItem::new_link(&name, self.url(), Some(("code", true)), Vec::new()).deprecated(
+            self.overloads
+                .iter()
+                .all(|fun| fun.get_deprecation().is_some()),
+        )
     }
 }
 
 impl<'e> ASTEntry<'e> for Function<'e> {
     fn entity(&self) -> &Entity<'e> {
-        &self.entity
+        &self.overloads[0]
     }
 
     fn category(&self) -> &'static str {
         "function"
     }
+
+    fn overloads(&self) -> Vec<Entity<'e>> {
+        self.overloads.clone()
+    }
 }
 
 impl<'e> OutputEntry<'e> for Function<'e> {
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::{html::Html, url::UrlPath};
+use clang::Entity;
+
+use super::{
+    builder::Builder,
+    shared::output_macro,
+    traits::{ASTEntry, BuildResult, EntityMethods, Entry, NavItem, OutputEntry},
+};
+
+pub struct Macro<'e> {
+    entity: Entity<'e>,
+}
+
+impl<'e> Macro<'e> {
+    pub fn new(entity: Entity<'e>) -> Self {
+        Self { entity }
+    }
+}
+
+impl<'e> Entry<'e> for Macro<'e> {
+    fn name(&self) -> String {
+        self.entity.get_name().unwrap_or("`Anonymous macro`".into())
+    }
+
+    fn url(&self) -> UrlPath {
+        self.entity.rel_docs_url().expect("Unable to get macro URL")
+    }
+
+    fn build(&self, builder: &Builder<'e>) -> BuildResult {
+        builder.create_output_for(self)
+    }
+
+    fn nav(&self) -> NavItem {
+        NavItem::new_link(&self.name(), self.url(), Some(("zap", false)), Vec::new())
+            .deprecated(self.entity.get_deprecation().is_some())
+    }
+}
+
+impl<'e> ASTEntry<'e> for Macro<'e> {
+    fn entity(&self) -> &Entity<'e> {
+        &self.entity
+    }
+
+    fn category(&self) -> &'static str {
+        "macro"
+    }
+}
+
+impl<'e> OutputEntry<'e> for Macro<'e> {
+    fn output(&self, builder: &Builder<'e>) -> (Arc<String>, Vec<(&'static str, Html)>) {
+        (
+            builder.config.templates.macro_.clone(),
+            output_macro(self, builder),
+        )
+    }
+
+    fn description(&self, builder: &'e Builder<'e>) -> String {
+        self.output_description(builder)
+    }
+}
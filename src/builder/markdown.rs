@@ -260,6 +260,27 @@ pub fn fmt_markdown<F: Fn(UrlPath) -> Option<UrlPath>>(
         .into()
 }
 
+/// Same as fmt_markdown, but for short text that goes inside other elements,
+/// so it isn't wrapped in paragraphs
+pub fn fmt_markdown_inline(builder: &Builder, text: &str) -> Html {
+    let parser = MDStream::<5, fn(UrlPath) -> Option<UrlPath>>::new(
+        pulldown_cmark::Parser::new_ext(text, pulldown_cmark::Options::all()),
+        None,
+        builder,
+        None,
+    )
+    .filter(|event| {
+        !matches!(
+            event,
+            Event::Start(Tag::Paragraph) | Event::End(Tag::Paragraph)
+        )
+    });
+
+    let mut content = String::new();
+    pulldown_cmark::html::push_html(&mut content, parser);
+    Html::Raw(content.trim().to_owned())
+}
+
 #[allow(clippy::ptr_arg)]
 pub fn extract_metadata_from_md(text: &String, default_title: Option<String>) -> Option<Metadata> {
     let (text, metadata) = parse_markdown_metadata(text);
@@ -1,12 +1,19 @@
+pub mod alias;
 #[allow(clippy::module_inception)]
 pub mod builder;
 pub mod class;
 pub mod comment;
+pub mod concept;
+pub mod coverage;
+pub mod deprecated;
+pub mod enum_;
 pub mod files;
 pub mod function;
+pub mod macro_;
 pub mod markdown;
 pub mod namespace;
 pub mod shared;
 pub mod struct_;
 pub mod traits;
 pub mod tutorial;
+pub mod variable;
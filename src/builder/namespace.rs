@@ -1,23 +1,33 @@
 use std::{collections::HashMap, sync::Arc};
 
-use clang::{Entity, EntityKind};
+use clang::{Accessibility, Entity, EntityKind};
 use log::{debug, warn};
 
 use crate::{config::Config, url::UrlPath};
 
 use super::{
+    alias::Alias,
     builder::Builder,
     class::Class,
+    concept::Concept,
+    enum_::Enum,
     function::Function,
+    macro_::Macro,
     struct_::Struct,
     traits::{ASTEntry, BuildResult, EntityMethods, Entry, NavItem},
+    variable::Variable,
 };
 
 pub enum CppItemKind {
     Namespace,
     Class,
     Struct,
+    Enum,
+    Alias,
+    Concept,
     Function,
+    Variable,
+    Macro,
 }
 
 impl CppItemKind {
@@ -27,8 +37,28 @@ impl CppItemKind {
             EntityKind::ClassDecl
             | EntityKind::ClassTemplate
             | EntityKind::ClassTemplatePartialSpecialization => Some(Self::Class),
+            EntityKind::EnumDecl => Some(Self::Enum),
+            EntityKind::TypedefDecl
+            | EntityKind::TypeAliasDecl
+            | EntityKind::TypeAliasTemplateDecl => Some(Self::Alias),
             EntityKind::FunctionDecl | EntityKind::FunctionTemplate => Some(Self::Function),
+            // static data members, including their out-of-line definitions,
+            // are documented with their class
+            EntityKind::VarDecl
+                if entity.get_semantic_parent().is_none_or(|parent| {
+                    matches!(
+                        parent.get_kind(),
+                        EntityKind::Namespace | EntityKind::TranslationUnit
+                    )
+                }) =>
+            {
+                Some(Self::Variable)
+            }
+            EntityKind::MacroDefinition => Some(Self::Macro),
             EntityKind::Namespace => Some(Self::Namespace),
+            EntityKind::NotImplemented | EntityKind::UnexposedDecl if entity.is_concept() => {
+                Some(Self::Concept)
+            }
             _ => None,
         }
     }
@@ -38,16 +68,114 @@ impl CppItemKind {
             Self::Namespace => "namespaces",
             Self::Class => "classes",
             Self::Struct => "classes",
+            Self::Enum => "enums",
+            Self::Alias => "aliases",
+            Self::Concept => "concepts",
             Self::Function => "functions",
+            Self::Variable => "variables",
+            Self::Macro => "macros",
         })
     }
 }
 
+/// Checks whether this entity should be documented at all, and returns its
+/// name if so
+fn entry_name(child: &Entity, config: &Arc<Config>) -> Option<String> {
+    // skip unnamed items
+    let child_name = child.get_name()?;
+    let full_child_name = child.full_name().join("::");
+
+    // skip stuff from external headers
+    if child.is_in_system_header() && child.get_allowed_external_lib(config.clone()).is_none() {
+        return None;
+    }
+
+    // skips specialization of std stuff or builtin stuff
+    if full_child_name.starts_with("std::")
+        || child_name.contains("deduction guide for")
+        || child_name.contains("unnamed ")
+    {
+        return None;
+    }
+
+    // if first char is weird
+    if child_name
+        .chars()
+        .next()
+        .is_some_and(|c| "()<>[]".contains(c))
+    {
+        warn!("{full_child_name:?} is probably an internal identifier, skipping");
+        return None;
+    }
+
+    // skip stuff that's been hidden in its doc comment, along with everything
+    // inside it
+    if child.is_hidden(config) {
+        debug!("skipping hidden {full_child_name}");
+        return None;
+    }
+
+    if let Some(ignore) = &config.ignore {
+        for pat in &ignore.patterns_full {
+            if pat.is_match(&full_child_name) {
+                debug!("skipping {full_child_name}");
+                return None;
+            }
+        }
+        for pat in &ignore.patterns_name {
+            if pat.is_match(&child_name) {
+                debug!("skipping {full_child_name}");
+                return None;
+            }
+        }
+    }
+
+    Some(child_name)
+}
+
+/// Loads the classes, structs, enums and type aliases declared inside a
+/// class-like entity
+pub fn load_member_types<'e>(
+    entity: &Entity<'e>,
+    config: Arc<Config>,
+) -> HashMap<String, CppItem<'e>> {
+    let mut entries = HashMap::new();
+    for child in &entity.get_children() {
+        if entry_name(child, &config).is_none() {
+            continue;
+        }
+
+        // private types aren't part of the API
+        if child.get_accessibility() == Some(Accessibility::Private) {
+            continue;
+        }
+
+        let entry = match CppItemKind::from(child) {
+            Some(CppItemKind::Struct) if child.is_definition() => {
+                CppItem::Struct(Struct::new(*child, config.clone()))
+            }
+            Some(CppItemKind::Class) if child.is_definition() => {
+                CppItem::Class(Class::new(*child, config.clone()))
+            }
+            Some(CppItemKind::Enum) if child.is_definition() => CppItem::Enum(Enum::new(*child)),
+            Some(CppItemKind::Alias) => CppItem::Alias(Alias::new(*child)),
+            _ => continue,
+        };
+        entries.insert(entry.name(), entry);
+    }
+    entries
+}
+
 pub enum CppItem<'e> {
     Namespace(Namespace<'e>),
     Class(Class<'e>),
     Struct(Struct<'e>),
+    Enum(Enum<'e>),
+    Alias(Alias<'e>),
+    Concept(Concept<'e>),
     Function(Function<'e>),
+    Variable(Variable<'e>),
+    Macro(Macro<'e>),
 }
 
 impl<'e> CppItem<'e> {
@@ -69,17 +197,48 @@ impl<'e> CppItem<'e> {
                 if matcher(cls) {
                     out.push(cls);
                 }
+                for entry in cls.entries.values() {
+                    entry.get(&matcher, out);
+                }
             }
             CppItem::Struct(cls) => {
                 if matcher(cls) {
                     out.push(cls);
                 }
+                for entry in cls.entries.values() {
+                    entry.get(&matcher, out);
+                }
+            }
+            CppItem::Enum(en) => {
+                if matcher(en) {
+                    out.push(en);
+                }
+            }
+            CppItem::Alias(al) => {
+                if matcher(al) {
+                    out.push(al);
+                }
+            }
+            CppItem::Concept(con) => {
+                if matcher(con) {
+                    out.push(con);
+                }
             }
             CppItem::Function(fun) => {
                 if matcher(fun) {
                     out.push(fun);
                 }
             }
+            CppItem::Variable(var) => {
+                if matcher(var) {
+                    out.push(var);
+                }
+            }
+            CppItem::Macro(mac) => {
+                if matcher(mac) {
+                    out.push(mac);
+                }
+            }
         }
     }
 }
@@ -90,7 +249,12 @@ impl<'e> Entry<'e> for CppItem<'e> {
             CppItem::Namespace(ns) => ns.name(),
             CppItem::Class(cs) => cs.name(),
             CppItem::Struct(st) => st.name(),
+            CppItem::Enum(en) => en.name(),
+            CppItem::Alias(al) => al.name(),
+            CppItem::Concept(con) => con.name(),
             CppItem::Function(st) => st.name(),
+            CppItem::Variable(var) => var.name(),
+            CppItem::Macro(mac) => mac.name(),
         }
     }
 
@@ -99,7 +263,12 @@ impl<'e> Entry<'e> for CppItem<'e> {
             CppItem::Namespace(ns) => ns.url(),
             CppItem::Class(cs) => cs.url(),
             CppItem::Struct(st) => st.url(),
+            CppItem::Enum(en) => en.url(),
+            CppItem::Alias(al) => al.url(),
+            CppItem::Concept(con) => con.url(),
             CppItem::Function(st) => st.url(),
+            CppItem::Variable(var) => var.url(),
+            CppItem::Macro(mac) => mac.url(),
         }
     }
 
@@ -108,7 +277,12 @@ impl<'e> Entry<'e> for CppItem<'e> {
             CppItem::Namespace(ns) => ns.build(builder),
             CppItem::Class(cs) => cs.build(builder),
             CppItem::Struct(st) => st.build(builder),
+            CppItem::Enum(en) => en.build(builder),
+            CppItem::Alias(al) => al.build(builder),
+            CppItem::Concept(con) => con.build(builder),
             CppItem::Function(st) => st.build(builder),
+            CppItem::Variable(var) => var.build(builder),
+            CppItem::Macro(mac) => mac.build(builder),
         }
     }
 
@@ -117,7 +291,12 @@ impl<'e> Entry<'e> for CppItem<'e> {
             CppItem::Namespace(ns) => ns.nav(),
             CppItem::Class(cs) => cs.nav(),
             CppItem::Struct(st) => st.nav(),
+            CppItem::Enum(en) => en.nav(),
+            CppItem::Alias(al) => al.nav(),
+            CppItem::Concept(con) => con.nav(),
             CppItem::Function(st) => st.nav(),
+            CppItem::Variable(var) => var.nav(),
+            CppItem::Macro(mac) => mac.nav(),
         }
     }
 }
@@ -129,6 +308,11 @@ impl<'e> ASTEntry<'e> for CppItem<'e> {
             CppItem::Function(c) => c.entity(),
             CppItem::Namespace(c) => c.entity(),
             CppItem::Struct(c) => c.entity(),
+            CppItem::Variable(c) => c.entity(),
+            CppItem::Macro(c) => c.entity(),
+            CppItem::Enum(c) => c.entity(),
+            CppItem::Alias(c) => c.entity(),
+            CppItem::Concept(c) => c.entity(),
         }
     }
 
@@ -137,15 +321,33 @@ impl<'e> ASTEntry<'e> for CppItem<'e> {
             CppItem::Namespace(ns) => ns.category(),
             CppItem::Class(cs) => cs.category(),
             CppItem::Struct(st) => st.category(),
+            CppItem::Enum(en) => en.category(),
+            CppItem::Alias(al) => al.category(),
+            CppItem::Concept(con) => con.category(),
             CppItem::Function(st) => st.category(),
+            CppItem::Variable(var) => var.category(),
+            CppItem::Macro(mac) => mac.category(),
         }
     }
 }
 
+/// Checks whether a macro is an object-like one without a body or docs,
+/// like an include guard, which is only there for the preprocessor
+fn is_empty_marker_macro(mac: &Entity) -> bool {
+    !mac.is_function_like_macro()
+        && mac.get_doc_comment().is_none()
+        && mac
+            .get_range()
+            .is_none_or(|range| range.tokenize().len() <= 1)
+}
+
 pub struct Namespace<'e> {
     entity: Entity<'e>,
     is_root: bool,
     pub entries: HashMap<String, CppItem<'e>>,
+    /// Macros don't live in any C++ scope, so they're kept apart from the
+    /// entries to not clash with functions or classes of the same name
+    pub macros: HashMap<String, CppItem<'e>>,
 }
 
 impl<'e> Namespace<'e> {
@@ -154,6 +356,7 @@ impl<'e> Namespace<'e> {
             entity,
             is_root: false,
             entries: HashMap::new(),
+            macros: HashMap::new(),
         };
         ret.load_entries(config);
         ret
@@ -164,6 +367,7 @@ impl<'e> Namespace<'e> {
             entity,
             is_root: true,
             entries: HashMap::new(),
+            macros: HashMap::new(),
         };
         ret.load_entries(config);
         ret.clean_empty_namespaces();
@@ -172,6 +376,7 @@ impl<'e> Namespace<'e> {
 
     fn merge_with_namespace(&mut self, other: Namespace<'e>) {
         assert_eq!(self.entity.get_name(), other.entity.get_name());
+        self.macros.extend(other.macros);
         for (name, other_entry) in other.entries {
             if matches!(other_entry, CppItem::Namespace(_))
                 && let Some(CppItem::Namespace(ns)) = self.entries.get_mut(&name)
@@ -180,6 +385,13 @@ impl<'e> Namespace<'e> {
                     unreachable!()
                 };
                 ns.merge_with_namespace(entry_ns);
+            } else if matches!(other_entry, CppItem::Function(_))
+                && let Some(CppItem::Function(fun)) = self.entries.get_mut(&name)
+            {
+                let CppItem::Function(entry_fun) = other_entry else {
+                    unreachable!()
+                };
+                fun.merge_with_function(entry_fun);
             } else {
                 self.entries.insert(name, other_entry);
             }
@@ -192,7 +404,7 @@ impl<'e> Namespace<'e> {
             let mut remove = false;
             if let Some(CppItem::Namespace(ns)) = self.entries.get_mut(&key) {
                 ns.clean_empty_namespaces();
-                if ns.entries.is_empty() {
+                if ns.entries.is_empty() && ns.macros.is_empty() {
                     remove = true;
                 }
             }
@@ -209,53 +421,11 @@ impl<'e> Namespace<'e> {
     }
 
     fn load_entries(&mut self, config: Arc<Config>) {
-        'entries: for child in &self.entity.get_children() {
-            // skip unnamed items
-            let Some(child_name) = child.get_name() else {
-                continue;
-            };
-            let full_child_name = child.full_name().join("::");
-
-            // skip stuff from external headers
-            if child.is_in_system_header()
-                && child.get_allowed_external_lib(config.clone()).is_none()
-            {
+        for child in &self.entity.get_children() {
+            if entry_name(child, &config).is_none() {
                 continue;
             }
 
-            // skips specialization of std stuff or builtin stuff
-            if full_child_name.starts_with("std::")
-                || child_name.contains("deduction guide for")
-                || child_name.contains("unnamed ")
-            {
-                continue;
-            }
-
-            // if first char is weird
-            if child_name
-                .chars()
-                .next()
-                .is_some_and(|c| "()<>[]".contains(c))
-            {
-                warn!("{full_child_name:?} is probably an internal identifier, skipping");
-                continue;
-            }
-
-            if let Some(ignore) = &config.ignore {
-                for pat in &ignore.patterns_full {
-                    if pat.is_match(&full_child_name) {
-                        debug!("skipping {full_child_name}");
-                        continue 'entries;
-                    }
-                }
-                for pat in &ignore.patterns_name {
-                    if pat.is_match(&child_name) {
-                        debug!("skipping {full_child_name}");
-                        continue 'entries;
-                    }
-                }
-            }
-
             if let Some(kind) = CppItemKind::from(child) {
                 match kind {
                     CppItemKind::Namespace => {
@@ -271,32 +441,78 @@ impl<'e> Namespace<'e> {
 
                     CppItemKind::Struct => {
                         if child.is_definition() {
-                            let entry = Struct::new(*child);
+                            let entry = Struct::new(*child, config.clone());
                             self.entries.insert(entry.name(), CppItem::Struct(entry));
                         }
                     }
 
                     CppItemKind::Class => {
                         if child.is_definition() {
-                            let entry = Class::new(*child);
+                            let entry = Class::new(*child, config.clone());
                             self.entries.insert(entry.name(), CppItem::Class(entry));
                         }
                     }
 
+                    CppItemKind::Enum => {
+                        if child.is_definition() {
+                            let entry = Enum::new(*child);
+                            self.entries.insert(entry.name(), CppItem::Enum(entry));
+                        }
+                    }
+
+                    CppItemKind::Alias => {
+                        let entry = Alias::new(*child);
+                        self.entries.insert(entry.name(), CppItem::Alias(entry));
+                    }
+
+                    CppItemKind::Concept => {
+                        let entry = Concept::new(*child);
+                        self.entries.insert(entry.name(), CppItem::Concept(entry));
+                    }
+
                     CppItemKind::Function => {
                         let entry = Function::new(*child);
-                        self.entries.insert(entry.name(), CppItem::Function(entry));
+                        // if we have some function with the same name, this is
+                        // another overload of it
+                        if let Some(CppItem::Function(fun)) = self.entries.get_mut(&entry.name()) {
+                            fun.add_overload(*child);
+                        } else {
+                            self.entries.insert(entry.name(), CppItem::Function(entry));
+                        }
+                    }
+
+                    CppItemKind::Variable => {
+                        let entry = Variable::new(*child);
+                        self.entries.insert(entry.name(), CppItem::Variable(entry));
+                    }
+
+                    CppItemKind::Macro => {
+                        // the preprocessing record contains every macro ever
+                        // defined, so only document the project's own ones,
+                        // and not include guards or other empty markers
+                        if !child.is_builtin_macro()
+                            && child.config_source(config.clone()).is_some()
+                            && !is_empty_marker_macro(child)
+                        {
+                            let entry = Macro::new(*child);
+                            self.macros.insert(entry.name(), CppItem::Macro(entry));
+                        }
                     }
                 }
             }
         }
     }
 
+    /// Gets every entry in this namespace, macros included
+    pub fn all_entries(&self) -> impl Iterator<Item = &CppItem<'e>> {
+        self.entries.values().chain(self.macros.values())
+    }
+
     // so apparently if you make this a <M: Fn(&dyn ASTEntry<'e>) -> bool>
     // rustc crashes
     pub fn get(&'e self, matcher: &dyn Fn(&dyn ASTEntry<'e>) -> bool) -> Vec<&'e dyn ASTEntry<'e>> {
         let mut res = Vec::new();
-        for entry in self.entries.values() {
+        for entry in self.all_entries() {
             entry.get(&matcher, &mut res);
         }
         res
@@ -306,14 +522,14 @@ impl<'e> Namespace<'e> {
 impl<'e> Entry<'e> for Namespace<'e> {
     fn build(&self, builder: &Builder<'e>) -> BuildResult {
         let mut handles = Vec::new();
-        for entry in self.entries.values() {
+        for entry in self.all_entries() {
             handles.extend(entry.build(builder)?);
         }
         Ok(handles)
     }
 
     fn nav(&self) -> NavItem {
-        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        let mut entries = self.entries.iter().chain(&self.macros).collect::<Vec<_>>();
 
         // Namespaces first in sorted order, everything else after in sorted order
         entries.sort_by_key(|p| (!matches!(p.1, CppItem::Namespace(_)), p.0));
@@ -1,16 +1,25 @@
 use super::builder::Builder;
-use super::comment::JSDocComment;
-use super::namespace::CppItem;
-use super::traits::{ASTEntry, Access, EntityMethods, Entry, Include};
+use super::comment::{get_comment_group, Deprecation, JSDocComment};
+use super::markdown::fmt_markdown;
+use super::namespace::{CppItem, CppItemKind};
+use super::traits::{get_file_contents, ASTEntry, Access, EntityMethods, Entry, Include};
 use crate::annotation::Annotations;
 use crate::builder::namespace::Namespace;
 use crate::config::Config;
 use crate::html::{Html, HtmlElement, HtmlList, HtmlText};
-use clang::{Accessibility, Entity, EntityKind, Type, TypeKind};
+use clang::{
+    source::SourceRange,
+    token::{Token, TokenKind},
+    Accessibility, Entity, EntityKind, EvaluationResult, ExceptionSpecification, RefQualifier,
+    Type, TypeKind,
+};
+use log::warn;
 use multipeek::{IteratorExt, MultiPeek};
 use pulldown_cmark::CowStr;
+use regex_lite::{Match, Regex};
+use std::collections::{HashMap, HashSet};
 use std::str::Chars;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 
 trait Surround<T> {
     fn surround(self, start: T, end: T) -> Self;
@@ -71,6 +80,7 @@ fn fmt_type(entity: &Type, builder: &Builder) -> Html {
                                 EntityKind::TypedefDecl => "alias",
                                 EntityKind::UsingDeclaration => "alias",
                                 EntityKind::TypeAliasDecl => "alias",
+                                EntityKind::TypeAliasTemplateDecl => "alias",
                                 EntityKind::EnumDecl => "enum",
                                 _ => "type",
                             })
@@ -164,14 +174,82 @@ fn fmt_param(param: &Entity, builder: &Builder) -> Html {
                 .get_display_name()
                 .map(|name| Html::span(&["name", "space-before"], &name)),
         )
+        .with_child_opt(param.get_default_value().map::<Html, _>(|value| {
+            HtmlList::new(vec![
+                Html::span(&["space-before"], "="),
+                Html::span(&["space-before", "literal"], &value),
+            ])
+            .into()
+        }))
         .into()
 }
 
-fn fmt_template_args(entity: &Entity, _builder: &Builder) -> Option<Html> {
+fn fmt_template_param(param: &Entity, builder: &Builder) -> Html {
+    // `class` or `typename`, the last one since template template parameters
+    // have their own template parameters first
+    let keyword = param
+        .get_leading_keywords()
+        .into_iter()
+        .rev()
+        .find(|k| k == "class" || k == "typename");
+
+    // type parameters constrained by a concept have it in place of the keyword
+    let constraint = || -> Option<Html> {
+        let name_offset = param.get_location().map(|l| l.get_file_location().offset);
+        let tokens = param
+            .get_range()?
+            .tokenize()
+            .into_iter()
+            .take_while(|t| Some(t.get_location().get_file_location().offset) != name_offset)
+            .filter(|t| t.get_spelling() != "...")
+            .collect::<Vec<_>>();
+        (!tokens.is_empty()).then(|| fmt_constraint(&tokens, builder))
+    };
+    let fmt_keyword = || match &keyword {
+        Some(kw) => Html::span(&["keyword"], kw),
+        None => constraint().unwrap_or(Html::span(&["keyword"], "typename")),
+    };
+
+    let mut parts = match param.get_kind() {
+        EntityKind::NonTypeTemplateParameter => param
+            .get_type()
+            .map(|t| fmt_type(&t, builder))
+            .into_iter()
+            .collect(),
+        EntityKind::TemplateTemplateParameter => fmt_template_args(param, builder)
+            .into_iter()
+            .chain([Html::span(
+                &["keyword"],
+                keyword.as_deref().unwrap_or("typename"),
+            )])
+            .collect(),
+        _ => vec![fmt_keyword()],
+    };
+    if param.is_parameter_pack() {
+        parts.push(HtmlText::new("...").into());
+    }
+    if let Some(name) = param.get_name() {
+        parts.push(Html::span(&["template-param", "space-before"], &name));
+    }
+    if let Some(value) = param.get_default_value() {
+        parts.push(Html::span(&["space-before"], "="));
+        parts.push(Html::span(&["space-before", "literal"], &value));
+    }
+    HtmlList::new(parts).into()
+}
+
+fn fmt_template_args(entity: &Entity, builder: &Builder) -> Option<Html> {
     let template_children: Vec<Entity> = entity
         .get_children()
         .into_iter()
-        .filter(|e| e.get_kind() == EntityKind::TemplateTypeParameter)
+        .filter(|e| {
+            matches!(
+                e.get_kind(),
+                EntityKind::TemplateTypeParameter
+                    | EntityKind::NonTypeTemplateParameter
+                    | EntityKind::TemplateTemplateParameter
+            )
+        })
         .collect();
     if template_children.is_empty() {
         return None;
@@ -182,15 +260,8 @@ fn fmt_template_args(entity: &Entity, _builder: &Builder) -> Option<Html> {
             .with_child(Html::span(&["keyword", "space-after"], "template"))
             .with_children(
                 template_children
-                    .into_iter()
-                    .map(|e| {
-                        HtmlText::new(
-                            e.extract_source_string_cleaned()
-                                .or_else(|| e.get_name().map(|x| format!("typename {x}")))
-                                .unwrap_or("_".into()),
-                        )
-                        .into()
-                    })
+                    .iter()
+                    .map(|e| fmt_template_param(e, builder))
                     .collect::<Vec<_>>()
                     .insert_between(|| {
                         HtmlElement::new("span")
@@ -205,19 +276,60 @@ fn fmt_template_args(entity: &Entity, _builder: &Builder) -> Option<Html> {
     )
 }
 
+fn fmt_field_signature(field: &Entity, builder: &Builder) -> Vec<Html> {
+    field
+        .get_leading_keywords()
+        .into_iter()
+        .filter(|kw| {
+            matches!(
+                kw.as_str(),
+                "static" | "inline" | "constexpr" | "constinit" | "thread_local" | "mutable"
+            )
+        })
+        .map(|kw| Html::span(&["keyword", "space-after"], &kw))
+        .chain(field.get_type().map(|t| fmt_type(&t, builder)))
+        .chain([Html::span(
+            &["name", "space-before"],
+            &field.get_name().unwrap_or("_anon".into()),
+        )])
+        .chain(field.get_bit_field_width().map(|width| {
+            HtmlList::new(vec![
+                Html::span(&["space-before"], ":"),
+                Html::span(&["space-before", "literal"], &width.to_string()),
+            ])
+            .into()
+        }))
+        // default member initializers and in-class initializers of static
+        // members, or the value of a constant initialized elsewhere
+        .chain(
+            field
+                .get_default_value()
+                .or_else(|| fmt_evaluated_value(field))
+                .map(|value| {
+                    HtmlList::new(vec![
+                        Html::span(&["space-before"], "="),
+                        Html::span(&["space-before", "literal"], &value),
+                    ])
+                    .into()
+                }),
+        )
+        .chain([HtmlText::new(";").into()])
+        .chain(fmt_deprecated_badge(field))
+        .collect()
+}
+
 pub fn fmt_field(field: &Entity, builder: &Builder) -> Html {
     HtmlElement::new("details")
         .with_class("entity-desc")
         .with_child(
             HtmlElement::new("summary")
                 .with_classes(&["entity", "var"])
-                .with_child(fmt_param(field, builder))
-                .with_child(HtmlText::new(";")),
+                .with_children(fmt_field_signature(field, builder)),
         )
         .with_child(
             HtmlElement::new("div").with_child(
                 field
-                    .get_comment()
+                    .get_doc_comment()
                     .map(|s| JSDocComment::parse(s, builder).to_html(true))
                     .unwrap_or(Html::span(&["no-desc"], "No description provided")),
             ),
@@ -225,13 +337,57 @@ pub fn fmt_field(field: &Entity, builder: &Builder) -> Html {
         .into()
 }
 
+fn fmt_noexcept(fun: &Entity, expression: Option<String>) -> Option<Html> {
+    match fun.get_exception_specification()? {
+        ExceptionSpecification::BasicNoexcept => {
+            Some(Html::span(&["keyword", "space-before"], "noexcept"))
+        }
+        ExceptionSpecification::ComputedNoexcept => Some(
+            HtmlList::new(vec![
+                Html::span(&["keyword", "space-before"], "noexcept"),
+                HtmlText::new(expression.unwrap_or("(...)".into())).into(),
+            ])
+            .into(),
+        ),
+        ExceptionSpecification::DynamicNone => Some(
+            HtmlList::new(vec![
+                Html::span(&["keyword", "space-before"], "throw"),
+                HtmlText::new("()").into(),
+            ])
+            .into(),
+        ),
+        _ => None,
+    }
+}
+
 fn fmt_fun_signature(fun: &Entity, builder: &Builder) -> Html {
+    let is_special_member = matches!(
+        fun.get_function_kind(),
+        EntityKind::Constructor | EntityKind::Destructor | EntityKind::ConversionFunction
+    );
+    let has_attr = |kind: EntityKind| fun.get_children().iter().any(|c| c.get_kind() == kind);
+    let tail = fun.get_function_tail();
+    let trailing_return = tail.trailing_return;
+    let keywords = fun.get_leading_keywords();
+
+    let requires_clause = fun.get_requires_clause();
+    let trailing_requires = is_trailing_requires_clause(fun, &requires_clause);
+
     HtmlElement::new("summary")
         .with_classes(&["entity", "fun"])
         .with_child_opt(fmt_template_args(fun, builder))
+        .with_child_opt(
+            (!trailing_requires)
+                .then(|| fmt_requires_clause(&requires_clause, builder))
+                .flatten(),
+        )
         .with_child(
             HtmlElement::new("span")
                 .with_class("function-signature")
+                .with_child_opt(
+                    has_attr(EntityKind::WarnUnusedResultAttr)
+                        .then_some(Html::span(&["attribute", "space-after"], "[[nodiscard]]")),
+                )
                 .with_child_opt(
                     fun.is_static_method()
                         .then_some(Html::span(&["keyword", "space-after"], "static")),
@@ -240,7 +396,22 @@ fn fmt_fun_signature(fun: &Entity, builder: &Builder) -> Html {
                     fun.is_virtual_method()
                         .then_some(Html::span(&["keyword", "space-after"], "virtual")),
                 )
-                .with_child_opt(fun.get_result_type().map(|t| fmt_type(&t, builder)))
+                .with_children(
+                    ["explicit", "constexpr", "consteval"]
+                        .into_iter()
+                        .filter(|kw| keywords.iter().any(|k| k == kw))
+                        .map(|kw| Html::span(&["keyword", "space-after"], kw))
+                        .collect(),
+                )
+                // constructors and destructors don't have a return type, and
+                // conversion functions have it in their name
+                .with_child_opt(if trailing_return {
+                    Some(Html::span(&["keyword"], "auto"))
+                } else {
+                    fun.get_result_type()
+                        .filter(|_| !is_special_member)
+                        .map(|t| fmt_type(&t, builder))
+                })
                 .with_child(Html::span(
                     &["name", "space-before"],
                     &fun.get_name().unwrap_or("_anon".into()),
@@ -262,6 +433,40 @@ fn fmt_fun_signature(fun: &Entity, builder: &Builder) -> Html {
                     fun.is_const_method()
                         .then_some(Html::span(&["keyword", "space-before"], "const")),
                 )
+                .with_child_opt(fun.get_type().and_then(|t| t.get_ref_qualifier()).map(
+                    |r| match r {
+                        RefQualifier::LValue => Html::span(&["space-before"], "&"),
+                        RefQualifier::RValue => Html::span(&["space-before"], "&&"),
+                    },
+                ))
+                .with_child_opt(fmt_noexcept(fun, tail.noexcept))
+                .with_child_opt(
+                    fun.get_result_type()
+                        .filter(|_| trailing_return)
+                        .map::<Html, _>(|t| {
+                            HtmlList::new(vec![
+                                Html::span(&["space-before", "space-after"], "->"),
+                                fmt_type(&t, builder),
+                            ])
+                            .into()
+                        }),
+                )
+                .with_child_opt(
+                    trailing_requires
+                        .then(|| fmt_requires_clause(&requires_clause, builder))
+                        .flatten()
+                        .map::<Html, _>(|clause| {
+                            HtmlList::new(vec![HtmlText::new(" ").into(), clause]).into()
+                        }),
+                )
+                .with_child_opt(
+                    has_attr(EntityKind::OverrideAttr)
+                        .then_some(Html::span(&["keyword", "space-before"], "override")),
+                )
+                .with_child_opt(
+                    has_attr(EntityKind::FinalAttr)
+                        .then_some(Html::span(&["keyword", "space-before"], "final")),
+                )
                 .with_child_opt(
                     fun.is_pure_virtual_method().then_some::<Html>(
                         HtmlList::new(vec![
@@ -270,26 +475,111 @@ fn fmt_fun_signature(fun: &Entity, builder: &Builder) -> Html {
                         ])
                         .into(),
                     ),
+                )
+                .with_child_opt(
+                    fun.is_defaulted().then_some::<Html>(
+                        HtmlList::new(vec![
+                            Html::span(&["space-before"], "="),
+                            Html::span(&["space-before", "keyword"], "default"),
+                        ])
+                        .into(),
+                    ),
+                )
+                .with_child_opt(
+                    tail.deleted.then_some::<Html>(
+                        HtmlList::new(vec![
+                            Html::span(&["space-before"], "="),
+                            Html::span(&["space-before", "keyword"], "delete"),
+                        ])
+                        .into(),
+                    ),
                 ),
         )
+        .with_child_opt(fmt_deprecated_badge(fun))
         .into()
 }
 
 pub fn fmt_class_method(fun: &Entity, builder: &Builder) -> Html {
+    fmt_fun_decl(fun, member_fun_link(fun, builder), builder)
+}
+
+fn fmt_fun_decl(fun: &Entity, id: Option<String>, builder: &Builder) -> Html {
     HtmlElement::new("details")
         .with_class("entity-desc")
-        .with_attr_opt("id", member_fun_link(fun))
+        .with_attr_opt("id", id)
         .with_child(fmt_fun_signature(fun, builder))
         .with_child(
             HtmlElement::new("div").with_child(
-                fun.get_comment()
-                    .map(|s| JSDocComment::parse(s, builder).to_html(true))
+                get_method_doc_comment(fun, builder)
+                    .map(|c| c.to_html(true))
                     .unwrap_or(Html::span(&["no-desc"], "No description provided")),
             ),
         )
         .into()
 }
 
+/// Warns about documented parameters that don't match the signatures of
+/// their entities, and about references that don't resolve to anything.
+/// This is done once per build, since the comments get rendered on many pages
+pub fn check_doc_comments(builder: &Builder) {
+    // inherited docs would report the same references again
+    let mut unresolved = HashSet::new();
+    for (entity, _) in builder
+        .root
+        .all_entries()
+        .flat_map(|entry| get_linkable(entry, builder))
+    {
+        if entity.get_kind() != EntityKind::Namespace
+            && let Some(comment) = get_method_doc_comment(&entity, builder)
+        {
+            for target in comment.check_signature(&entity).unresolved_references() {
+                if unresolved.insert(target.clone()) {
+                    warn!("Unable to resolve reference to {target}");
+                }
+            }
+        }
+    }
+}
+
+/// Gets the doc comment of a method, or any other entity. Overrides without
+/// docs of their own inherit the docs of the method they override, and
+/// `@inheritdoc` merges them into the override's docs. Functions without a
+/// doc comment may still have their parameters documented inline
+pub fn get_method_doc_comment<'b>(
+    fun: &Entity,
+    builder: &'b Builder<'b>,
+) -> Option<JSDocComment<'b>> {
+    let own = fun
+        .get_doc_comment()
+        .map(|s| JSDocComment::parse(s, builder));
+    if own.as_ref().is_some_and(|c| !c.inherits()) {
+        return own.map(|c| c.match_signature(fun));
+    }
+    let Some((base, base_comment)) = fun
+        .get_overridden_methods()
+        .unwrap_or_default()
+        .into_iter()
+        .find_map(|base| Some((base, get_method_doc_comment(&base, builder)?)))
+    else {
+        return match own {
+            Some(own) => Some(own.match_signature(fun)),
+            None => {
+                Some(JSDocComment::new(builder).match_signature(fun)).filter(|c| c.has_params())
+            }
+        };
+    };
+    // the base's params are only known to match once they're merged in
+    Some(
+        own.unwrap_or_else(|| JSDocComment::new(builder))
+            .inherit_from(
+                base_comment,
+                base.full_name().join("::"),
+                member_docs_url(&base, builder),
+            )
+            .match_signature(fun),
+    )
+}
+
 pub fn fmt_classlike_decl(class: &Entity, keyword: &str, builder: &Builder) -> Html {
     HtmlElement::new("details")
         .with_class("entity-desc")
@@ -302,184 +592,2279 @@ pub fn fmt_classlike_decl(class: &Entity, keyword: &str, builder: &Builder) -> H
                     &class.get_name().unwrap_or("_anon".into()),
                 ))
                 .with_child_opt(fmt_template_args(class, builder))
-                .with_child(HtmlText::new(";")),
+                .with_child(HtmlText::new(";"))
+                .with_child_opt(fmt_deprecated_badge(class)),
         )
         .with_child(
             HtmlElement::new("div").with_child(
-                class
-                    .get_comment()
-                    .map(|s| JSDocComment::parse(s, builder).to_html(true))
+                get_method_doc_comment(class, builder)
+                    .map(|c| c.to_html(true))
                     .unwrap_or(Html::span(&["no-desc"], "No description provided")),
             ),
         )
         .into()
 }
 
-pub fn fmt_section(title: &str, data: Vec<Html>) -> Html {
+fn fmt_enum_value(enumerator: &Entity, signed: bool) -> Option<String> {
+    enumerator
+        .get_enum_constant_value()
+        .map(|(s, u)| if signed { s.to_string() } else { u.to_string() })
+}
+
+pub fn fmt_enumerator(enumerator: &Entity, signed: bool, builder: &Builder) -> Html {
     HtmlElement::new("details")
-        .with_attr("open", "")
-        .with_class("section")
+        .with_class("entity-desc")
+        .with_attr_opt("id", enumerator.get_name())
         .with_child(
-            HtmlElement::new("summary").with_child(
-                HtmlElement::new("span")
-                    .with_child(Html::feather("chevron-right"))
-                    .with_child(HtmlText::new(title))
-                    .with_child(Html::span(&["badge"], &data.len().to_string())),
+            HtmlElement::new("summary")
+                .with_classes(&["entity", "var"])
+                .with_child(Html::span(
+                    &["name"],
+                    &enumerator.get_name().unwrap_or("_anon".into()),
+                ))
+                .with_child_opt(fmt_enum_value(enumerator, signed).map(|value| {
+                    HtmlList::new(vec![
+                        Html::span(&["space-before"], "="),
+                        Html::span(&["space-before", "literal"], &value),
+                    ])
+                }))
+                .with_child(HtmlText::new(","))
+                .with_child_opt(fmt_deprecated_badge(enumerator)),
+        )
+        .with_child(
+            HtmlElement::new("div").with_child(
+                enumerator
+                    .get_doc_comment()
+                    .map(|s| JSDocComment::parse(s, builder).to_html(true))
+                    .unwrap_or(Html::span(&["no-desc"], "No description provided")),
             ),
         )
-        .with_child(HtmlElement::new("div").with_child(HtmlList::new(data)))
         .into()
 }
 
-pub fn fmt_header_link(entity: &Entity, config: Arc<Config>) -> Html {
-    if let Some(link) = entity.github_url(config.clone())
-        && let Some(path) = entity.include_path(config.clone())
+/// Whether an enum's underlying type is spelled out in its declaration, as
+/// clang reports one for every enum even if it was never declared
+fn has_explicit_underlying_type(entity: &Entity) -> bool {
+    let Some(range) = entity.get_range() else {
+        return false;
+    };
+    // only the head of the declaration is relevant
+    let head = match entity
+        .get_children()
+        .iter()
+        .find(|child| child.get_kind() == EntityKind::EnumConstantDecl)
     {
-        let exists_online = entity
-            .config_source(config.clone())
-            .map(|s| s.exists_online)
-            .unwrap_or(true);
-        let disabled = !exists_online;
-        HtmlElement::new("a")
-            .with_attr_opt("href", (!disabled).then_some(link))
-            .with_class("header-link")
-            .with_class_opt(disabled.then_some("disabled"))
-            .with_child(
-                HtmlElement::new("code")
-                    .with_class("header-link")
-                    .with_children(vec![
-                        Html::span(&["keyword"], "#include "),
-                        Html::span(&["url"], &format!("&lt;{}&gt;", path.to_raw_string())),
-                    ]),
-            )
-            .into()
-    } else {
-        Html::p("&lt;Not available online&gt;")
-    }
+        Some(first) => SourceRange::new(
+            range.get_start(),
+            first.get_range().unwrap_or(range).get_start(),
+        ),
+        None => range,
+    };
+    head.tokenize()
+        .iter()
+        .map(|token| token.get_spelling())
+        .take_while(|spelling| spelling != "{" && spelling != ";")
+        .any(|spelling| spelling == ":")
 }
 
-pub fn fmt_base_classes<'e, T: ASTEntry<'e>>(entry: &T, kw: &str, builder: &Builder) -> Html {
-    let bases = entry
-        .entity()
-        .get_children()
-        .into_iter()
-        .filter(|p| p.get_kind() == EntityKind::BaseSpecifier)
-        .collect::<Vec<_>>();
-
+pub fn fmt_enum_decl(entity: &Entity, builder: &Builder) -> Html {
     HtmlElement::new("div")
-        .with_classes(&["entity", "class"])
-        .with_child_opt(fmt_template_args(entry.entity(), builder))
+        .with_classes(&["entity", "enum"])
         .with_child(
             HtmlElement::new("span")
                 .with_class("class-decl")
-                .with_child(Html::span(&["keyword", "space-after"], kw))
+                .with_child(Html::span(&["keyword", "space-after"], "enum"))
+                .with_child_opt(
+                    entity
+                        .is_scoped()
+                        .then_some(Html::span(&["keyword", "space-after"], "class")),
+                )
                 .with_child(Html::span(
                     &["name"],
-                    entry.entity().get_name().unwrap_or("_".into()).as_str(),
+                    entity.get_name().unwrap_or("_".into()).as_str(),
                 ))
-                .with_child_opt(
-                    (!bases.is_empty())
-                        .then_some(Html::span(&["space-before", "space-after"], ":")),
-                )
                 .with_children(
-                    bases
-                        .into_iter()
-                        .map(|base| {
-                            HtmlList::new(
-                                [
-                                    base.get_accessibility().map(|a| {
-                                        Html::span(
-                                            &["keyword", "space-after"],
-                                            match a {
-                                                Accessibility::Public => "public",
-                                                Accessibility::Private => "private",
-                                                Accessibility::Protected => "protected",
-                                            },
-                                        )
-                                    }),
-                                    base.is_virtual_base().then_some(Html::span(
-                                        &["keyword", "space-after"],
-                                        "virtual",
-                                    )),
-                                    base.get_type().map(|ty| fmt_type(&ty, builder)),
-                                ]
-                                .into_iter()
-                                .flatten()
-                                .collect(),
-                            )
-                            .into()
+                    entity
+                        .get_enum_underlying_type()
+                        .filter(|_| has_explicit_underlying_type(entity))
+                        .map(|ty| {
+                            vec![
+                                Html::span(&["space-before", "space-after"], ":"),
+                                fmt_type(&ty, builder),
+                            ]
                         })
-                        .intersperse_with(|| Html::span(&["space-after"], ","))
-                        .collect(),
+                        .unwrap_or_default(),
                 )
-                .with_child(Html::span(&["space-before"], "{ ... }")),
+                .with_child(Html::span(&["space-before"], "{ ... }"))
+                .with_child_opt(fmt_deprecated_badge(entity)),
+        )
+        .into()
+}
+
+fn alias_underlying_type<'e>(alias: &Entity<'e>) -> Option<Type<'e>> {
+    if alias.get_kind() == EntityKind::TypeAliasTemplateDecl {
+        alias
+            .get_children()
+            .into_iter()
+            .find(|child| child.get_kind() == EntityKind::TypeAliasDecl)?
+            .get_typedef_underlying_type()
+    } else {
+        alias.get_typedef_underlying_type()
+    }
+}
+
+fn fmt_alias_signature(alias: &Entity, builder: &Builder) -> Vec<Html> {
+    vec![
+        Html::span(&["keyword", "space-after"], "using"),
+        Html::span(&["name"], &alias.get_name().unwrap_or("_anon".into())),
+        Html::span(&["space-before", "space-after"], "="),
+    ]
+    .into_iter()
+    .chain(alias_underlying_type(alias).map(|ty| fmt_type(&ty, builder)))
+    .chain([HtmlText::new(";").into()])
+    .chain(fmt_deprecated_badge(alias))
+    .collect()
+}
+
+pub fn fmt_alias_decl(alias: &Entity, builder: &Builder) -> Html {
+    HtmlElement::new("div")
+        .with_classes(&["entity", "alias"])
+        .with_child_opt(fmt_template_args(alias, builder))
+        .with_child(
+            HtmlElement::new("span")
+                .with_class("class-decl")
+                .with_children(fmt_alias_signature(alias, builder)),
+        )
+        .into()
+}
+
+pub fn fmt_alias(alias: &Entity, builder: &Builder) -> Html {
+    HtmlElement::new("details")
+        .with_class("entity-desc")
+        .with_child(
+            HtmlElement::new("summary")
+                .with_classes(&["entity", "alias"])
+                .with_child_opt(fmt_template_args(alias, builder))
+                .with_child(
+                    HtmlElement::new("span")
+                        .with_class("class-decl")
+                        .with_children(fmt_alias_signature(alias, builder)),
+                ),
+        )
+        .with_child(
+            HtmlElement::new("div").with_child(
+                get_method_doc_comment(alias, builder)
+                    .map(|c| c.to_html(true))
+                    .unwrap_or(Html::span(&["no-desc"], "No description provided")),
+            ),
+        )
+        .into()
+}
+
+/// Formats a piece of source code like a constraint expression, linking any
+/// concepts it names
+fn fmt_constraint(tokens: &[Token], builder: &Builder) -> Html {
+    let mut parts = Vec::new();
+    let mut prev_end = None;
+    for token in tokens {
+        let range = token.get_range();
+        if prev_end.is_some_and(|end| end < range.get_start().get_file_location().offset) {
+            parts.push(HtmlText::new(" ").into());
+        }
+        prev_end = Some(range.get_end().get_file_location().offset);
+
+        let spelling = token.get_spelling();
+        parts.push(match token.get_kind() {
+            TokenKind::Identifier => match builder.names().find_concept(&spelling) {
+                Some(url) => HtmlElement::new("a")
+                    .with_classes(&["concept", "name"])
+                    .with_attr("href", url)
+                    .with_text(spelling)
+                    .into(),
+                None => HtmlText::new(spelling).into(),
+            },
+            TokenKind::Keyword => Html::span(&["keyword"], &spelling),
+            TokenKind::Literal => Html::span(&["literal"], &spelling),
+            _ => HtmlText::new(spelling).into(),
+        });
+    }
+    HtmlList::new(parts).into()
+}
+
+fn fmt_requires_clause(clause: &[Token], builder: &Builder) -> Option<Html> {
+    if clause.is_empty() {
+        return None;
+    }
+    Some(
+        HtmlElement::new("span")
+            .with_class("requires-clause")
+            .with_child(Html::span(&["keyword", "space-after"], "requires"))
+            .with_child(fmt_constraint(clause, builder))
+            .into(),
+    )
+}
+
+/// Checks if an entity's requires clause comes after its declarator
+fn is_trailing_requires_clause(entity: &Entity, clause: &[Token]) -> bool {
+    let name_offset = entity.get_location().map(|l| l.get_file_location().offset);
+    clause
+        .first()
+        .is_some_and(|t| Some(t.get_location().get_file_location().offset) > name_offset)
+}
+
+fn fmt_concept_signature(concept: &Entity, builder: &Builder) -> Vec<Html> {
+    let Some(range) = concept.get_range() else {
+        return Vec::new();
+    };
+    let tokens = range.tokenize();
+    let keyword = tokens
+        .iter()
+        .position(|t| t.get_spelling() == "concept")
+        .unwrap_or(0);
+    let eq = tokens
+        .iter()
+        .position(|t| t.get_spelling() == "=")
+        .unwrap_or(tokens.len());
+    // older LibClang versions include the token right after the range too
+    let end = tokens
+        .iter()
+        .rposition(|t| t.get_spelling() != ";")
+        .map(|i| i + 1)
+        .unwrap_or(tokens.len())
+        .max(eq);
+
+    vec![
+        // LibClang doesn't expose the template parameters of concepts on all
+        // versions, so fall back to the source of the template header
+        fmt_template_args(concept, builder)
+            .unwrap_or_else(|| fmt_constraint(&tokens[..keyword], builder)),
+        Html::span(&["space-before", "keyword", "space-after"], "concept"),
+        Html::span(&["name"], &concept.get_name().unwrap_or("_anon".into())),
+        Html::span(&["space-before", "space-after"], "="),
+        fmt_constraint(tokens.get(eq + 1..end).unwrap_or_default(), builder),
+        HtmlText::new(";").into(),
+    ]
+    .into_iter()
+    .chain(fmt_deprecated_badge(concept))
+    .collect()
+}
+
+pub fn fmt_concept_decl(concept: &Entity, builder: &Builder) -> Html {
+    HtmlElement::new("div")
+        .with_classes(&["entity", "concept"])
+        .with_child(
+            HtmlElement::new("span")
+                .with_class("class-decl")
+                .with_children(fmt_concept_signature(concept, builder)),
         )
         .into()
 }
 
-pub fn fmt_derived_class(entity: &Entity, builder: &Builder) -> Html {
-    HtmlElement::new("div")
-        .with_classes(&["entity", "class"])
+pub fn fmt_concept(concept: &Entity, builder: &Builder) -> Html {
+    HtmlElement::new("details")
+        .with_class("entity-desc")
+        .with_child(
+            HtmlElement::new("summary")
+                .with_classes(&["entity", "concept"])
+                .with_child(
+                    HtmlElement::new("span")
+                        .with_class("class-decl")
+                        .with_children(fmt_concept_signature(concept, builder)),
+                ),
+        )
+        .with_child(
+            HtmlElement::new("div").with_child(
+                get_method_doc_comment(concept, builder)
+                    .map(|c| c.to_html(true))
+                    .unwrap_or(Html::span(&["no-desc"], "No description provided")),
+            ),
+        )
+        .into()
+}
+
+fn fmt_evaluated_value(entity: &Entity) -> Option<String> {
+    let is_bool = entity
+        .get_type()
+        .is_some_and(|t| t.get_canonical_type().get_kind() == TypeKind::Bool);
+    match entity.evaluate()? {
+        EvaluationResult::SignedInteger(v) if is_bool => Some((v != 0).to_string()),
+        EvaluationResult::UnsignedInteger(v) if is_bool => Some((v != 0).to_string()),
+        EvaluationResult::SignedInteger(v) => Some(v.to_string()),
+        EvaluationResult::UnsignedInteger(v) => Some(v.to_string()),
+        EvaluationResult::Float(v) => Some(v.to_string()),
+        EvaluationResult::String(v) => Some(format!("{:?}", v.to_string_lossy())),
+        _ => None,
+    }
+}
+
+fn fmt_variable_signature(var: &Entity, builder: &Builder) -> Vec<Html> {
+    var.get_leading_keywords()
+        .into_iter()
+        .filter(|kw| {
+            matches!(
+                kw.as_str(),
+                "static" | "extern" | "inline" | "constexpr" | "constinit" | "thread_local"
+            )
+        })
+        .map(|kw| Html::span(&["keyword", "space-after"], &kw))
+        .chain(var.get_type().map(|t| fmt_type(&t, builder)))
+        .chain([Html::span(
+            &["name", "space-before"],
+            &var.get_name().unwrap_or("_anon".into()),
+        )])
+        .chain(fmt_evaluated_value(var).map(|value| {
+            HtmlList::new(vec![
+                Html::span(&["space-before"], "="),
+                Html::span(&["space-before", "literal"], &value),
+            ])
+            .into()
+        }))
+        .chain([HtmlText::new(";").into()])
+        .chain(fmt_deprecated_badge(var))
+        .collect()
+}
+
+pub fn fmt_variable_decl(var: &Entity, builder: &Builder) -> Html {
+    HtmlElement::new("div")
+        .with_classes(&["entity", "var"])
+        .with_children(fmt_variable_signature(var, builder))
+        .into()
+}
+
+pub fn fmt_variable(var: &Entity, builder: &Builder) -> Html {
+    HtmlElement::new("details")
+        .with_class("entity-desc")
+        .with_child(
+            HtmlElement::new("summary")
+                .with_classes(&["entity", "var"])
+                .with_children(fmt_variable_signature(var, builder)),
+        )
+        .with_child(
+            HtmlElement::new("div").with_child(
+                var.get_doc_comment()
+                    .map(|s| JSDocComment::parse(s, builder).to_html(true))
+                    .unwrap_or(Html::span(&["no-desc"], "No description provided")),
+            ),
+        )
+        .into()
+}
+
+/// Splits a macro definition into its parameters (if it's function-like) and
+/// its replacement text
+fn macro_definition_parts(mac: &Entity) -> (Option<Vec<String>>, String) {
+    let source = mac
+        .extract_source_string()
+        .unwrap_or_default()
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .replace("\\\n", "\n");
+    let name_len = mac.get_name().map(|n| n.len()).unwrap_or(0);
+    let rest = source.get(name_len..).unwrap_or_default();
+
+    if mac.is_function_like_macro()
+        && let Some((params, body)) = rest.strip_prefix('(').and_then(|rest| rest.split_once(')'))
+    {
+        (
+            Some(
+                params
+                    .split(',')
+                    .map(|p| p.trim().to_owned())
+                    .filter(|p| !p.is_empty())
+                    .collect(),
+            ),
+            body.trim().to_owned(),
+        )
+    } else {
+        (None, rest.trim().to_owned())
+    }
+}
+
+fn fmt_macro_signature(mac: &Entity) -> Vec<Html> {
+    let (params, _) = macro_definition_parts(mac);
+    vec![
+        Html::span(&["keyword", "space-after"], "#define"),
+        Html::span(&["name"], &mac.get_name().unwrap_or("_anon".into())),
+    ]
+    .into_iter()
+    .chain(params.map(|params| {
+        HtmlElement::new("span")
+            .with_class("params")
+            .with_children(
+                params
+                    .iter()
+                    .map(|p| Html::span(&["template-param"], p))
+                    .collect::<Vec<_>>()
+                    .insert_between(|| Html::span(&["comma", "space-after"], ","))
+                    .surround(HtmlText::new("(").into(), HtmlText::new(")").into()),
+            )
+            .into()
+    }))
+    .chain(fmt_deprecated_badge(mac))
+    .collect()
+}
+
+pub fn fmt_macro_decl(mac: &Entity) -> Html {
+    HtmlElement::new("div")
+        .with_classes(&["entity", "macro"])
+        .with_child(
+            HtmlElement::new("span")
+                .with_class("function-signature")
+                .with_children(fmt_macro_signature(mac)),
+        )
+        .into()
+}
+
+pub fn fmt_macro(mac: &Entity, builder: &Builder) -> Html {
+    HtmlElement::new("details")
+        .with_class("entity-desc")
+        .with_child(
+            HtmlElement::new("summary")
+                .with_classes(&["entity", "macro"])
+                .with_child(
+                    HtmlElement::new("span")
+                        .with_class("function-signature")
+                        .with_children(fmt_macro_signature(mac)),
+                ),
+        )
+        .with_child(
+            HtmlElement::new("div").with_child(
+                mac.get_doc_comment()
+                    .map(|s| JSDocComment::parse(s, builder).to_html(true))
+                    .unwrap_or(Html::span(&["no-desc"], "No description provided")),
+            ),
+        )
+        .into()
+}
+
+pub fn fmt_macro_replacement(mac: &Entity) -> Html {
+    let (_, body) = macro_definition_parts(mac);
+    if body.is_empty() {
+        return Html::p("");
+    }
+    HtmlElement::new("pre")
+        .with_child(
+            HtmlElement::new("code")
+                .with_class("language-cpp")
+                .with_text(body),
+        )
+        .into()
+}
+
+fn is_polymorphic(class: &Entity) -> bool {
+    class
+        .get_children()
+        .iter()
+        .any(|child| match child.get_kind() {
+            EntityKind::BaseSpecifier => child
+                .get_type()
+                .and_then(|t| t.get_declaration())
+                .and_then(|d| d.get_definition())
+                .is_some_and(|base| is_polymorphic(&base)),
+            _ => child.is_virtual_method(),
+        })
+}
+
+fn fmt_layout_offset(bits: usize) -> String {
+    if bits.is_multiple_of(8) {
+        format!("{:#x}", bits / 8)
+    } else {
+        format!("{:#x}:{}", bits / 8, bits % 8)
+    }
+}
+
+fn fmt_layout_size(bits: usize) -> String {
+    if bits.is_multiple_of(8) {
+        format!("{}", bits / 8)
+    } else {
+        format!("{bits} bits")
+    }
+}
+
+/// Gets the definitions of the direct bases of a class, along with whether
+/// they're inherited virtually
+fn get_base_definitions<'e>(class: &Entity<'e>) -> Vec<(bool, Entity<'e>)> {
+    class
+        .get_children()
+        .into_iter()
+        .filter(|child| child.get_kind() == EntityKind::BaseSpecifier)
+        .filter_map(|child| {
+            let base = child.get_type()?.get_declaration()?.get_definition()?;
+            Some((child.is_virtual_base(), base))
+        })
+        .collect()
+}
+
+/// Whether a class has no data at all, so that it takes up no space as a base
+fn is_empty_class(class: &Entity) -> bool {
+    !is_polymorphic(class)
+        && !has_virtual_bases(class)
+        && class
+            .get_children()
+            .iter()
+            .all(|child| match child.get_kind() {
+                EntityKind::FieldDecl => child.get_bit_field_width() == Some(0),
+                _ => true,
+            })
+        && get_base_definitions(class)
+            .iter()
+            .all(|(_, base)| is_empty_class(base))
+}
+
+/// Whether a class needs a vtable pointer, either for its virtual methods or
+/// to find its virtual bases
+fn is_dynamic_class(class: &Entity) -> bool {
+    is_polymorphic(class) || has_virtual_bases(class)
+}
+
+fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align.max(1)) * align.max(1)
+}
+
+/// Where a non-virtual base subobject is placed in its derived class
+struct BaseLayout<'e> {
+    base: Entity<'e>,
+    /// Offset of the subobject in bits
+    offset: usize,
+    /// Size of the subobject in bits, without the tail padding that the
+    /// derived class may reuse
+    size: usize,
+}
+
+/// The part of a class's layout that comes before its fields, which LibClang
+/// doesn't expose and is worked out here from the ABI's layout rules
+struct NonVirtualLayout<'e> {
+    /// Offset of the vtable pointer, if the class has one of its own instead
+    /// of sharing one with its primary base
+    vtable_pointer: Option<usize>,
+    /// Offset of the virtual base pointer, which only MSVC has
+    vbase_pointer: Option<usize>,
+    bases: Vec<BaseLayout<'e>>,
+    /// Where the class's own fields may start
+    end: usize,
+    /// Whether the layout rules used here cover everything about the class.
+    /// Virtual inheritance and MSVC's handling of empty bases aren't modeled
+    exact: bool,
+}
+
+/// Gets the end of the last field of a class in bits, rounded up to a byte
+fn fields_end(class: &Entity) -> usize {
+    class
+        .get_children()
+        .iter()
+        .filter(|child| child.get_kind() == EntityKind::FieldDecl)
+        .filter_map(|field| {
+            let size = match field.get_bit_field_width() {
+                Some(width) => width,
+                None => field.get_type()?.get_sizeof().ok()? * 8,
+            };
+            Some(field.get_offset_of_field().ok()? + size)
+        })
+        .max()
+        .map(|end| align_to(end, 8))
+        .unwrap_or(0)
+}
+
+/// Gets the size of a class when it's a base subobject, which leaves out its
+/// virtual bases. The Itanium ABI also lets the derived class reuse the tail
+/// padding of bases that aren't PODs
+fn base_subobject_size(class: &Entity, abi: Abi) -> usize {
+    if is_empty_class(class) {
+        return 0;
+    }
+    let Some(ty) = class.get_type() else {
+        return 0;
+    };
+    let size = ty.get_sizeof().unwrap_or(0) * 8;
+    match abi {
+        Abi::Itanium if !ty.is_pod() => get_non_virtual_layout(class, abi)
+            .end
+            .max(fields_end(class)),
+        _ if has_virtual_bases(class) => get_non_virtual_layout(class, abi)
+            .end
+            .max(fields_end(class)),
+        _ => size,
+    }
+}
+
+/// Collects the type and offset of every base subobject in a class,
+/// recursively, since two subobjects of the same type may never share an
+/// address
+fn collect_subobjects(class: &Entity, offset: usize, abi: Abi, out: &mut Vec<(String, usize)>) {
+    out.push((class.get_usr().map(|usr| usr.0).unwrap_or_default(), offset));
+    for base in get_non_virtual_layout(class, abi).bases {
+        collect_subobjects(&base.base, offset + base.offset, abi, out);
+    }
+}
+
+/// Works out where the vtable pointer and non-virtual bases of a class are
+/// placed, following the layout rules of the ABI
+fn get_non_virtual_layout<'e>(class: &Entity<'e>, abi: Abi) -> NonVirtualLayout<'e> {
+    let pointer_size = class.get_translation_unit().get_target().pointer_width;
+    let class_align = class
+        .get_type()
+        .and_then(|t| t.get_alignof().ok())
+        .unwrap_or(1)
+        * 8;
+    let all_bases = get_base_definitions(class);
+    let mut bases = all_bases
+        .iter()
+        .filter(|(is_virtual, _)| !is_virtual)
+        .map(|(_, base)| *base)
+        .collect::<Vec<_>>();
+
+    let mut layout = NonVirtualLayout {
+        vtable_pointer: None,
+        vbase_pointer: None,
+        bases: Vec::new(),
+        end: 0,
+        exact: true,
+    };
+    // every base subobject placed so far, with its offset
+    let mut subobjects = Vec::new();
+    let place = |layout: &mut NonVirtualLayout<'e>,
+                 subobjects: &mut Vec<(String, usize)>,
+                 base: Entity<'e>,
+                 offset: usize| {
+        let size = base_subobject_size(&base, abi);
+        // empty bases don't take up any space the fields could use
+        if size > 0 {
+            layout.end = layout.end.max(offset + size);
+        }
+        collect_subobjects(&base, offset, abi, subobjects);
+        layout.bases.push(BaseLayout { base, offset, size });
+    };
+    let conflicts = |subobjects: &[(String, usize)], base: &Entity, offset: usize| {
+        let mut placed = Vec::new();
+        collect_subobjects(base, offset, abi, &mut placed);
+        placed.iter().any(|sub| subobjects.contains(sub))
+    };
+
+    match abi {
+        Abi::Itanium => {
+            // the first non-virtual dynamic base is the primary base, which
+            // shares its vtable pointer with the class
+            let primary = is_dynamic_class(class)
+                .then(|| bases.iter().position(is_dynamic_class))
+                .flatten();
+            match primary {
+                Some(primary) => place(&mut layout, &mut subobjects, bases.remove(primary), 0),
+                None if is_dynamic_class(class) => {
+                    layout.vtable_pointer = Some(0);
+                    layout.end = pointer_size;
+                    // a nearly empty virtual base could be primary instead
+                    layout.exact = !has_virtual_bases(class);
+                }
+                None => {}
+            }
+            for base in bases {
+                let align = base
+                    .get_type()
+                    .and_then(|t| t.get_alignof().ok())
+                    .unwrap_or(1)
+                    * 8;
+                // empty bases go at the very start whenever they can
+                let mut offset = if is_empty_class(&base) {
+                    0
+                } else {
+                    align_to(layout.end, align)
+                };
+                while conflicts(&subobjects, &base, offset) {
+                    offset = if offset < layout.end {
+                        align_to(layout.end, align)
+                    } else {
+                        offset + align
+                    };
+                }
+                place(&mut layout, &mut subobjects, base, offset);
+            }
+        }
+        Abi::Msvc => {
+            // bases with vtable pointers come first, and the first of those
+            // shares its vtable pointer with the class
+            bases.sort_by_key(|base| !is_polymorphic(base));
+            if is_polymorphic(class) && !bases.first().is_some_and(is_polymorphic) {
+                layout.vtable_pointer = Some(0);
+                layout.end = align_to(pointer_size, class_align);
+            }
+            let mut leading_empty = true;
+            for base in bases {
+                let align = base
+                    .get_type()
+                    .and_then(|t| t.get_alignof().ok())
+                    .unwrap_or(1)
+                    * 8;
+                if is_empty_class(&base) {
+                    // only empty bases before any data are known to take up
+                    // no space
+                    layout.exact &= leading_empty;
+                } else {
+                    leading_empty = false;
+                }
+                let offset = align_to(layout.end, align);
+                place(&mut layout, &mut subobjects, base, offset);
+            }
+            // the virtual base pointer is shared with the first base that
+            // has one, or added after the bases
+            if has_virtual_bases(class) {
+                layout.exact = false;
+                if !layout
+                    .bases
+                    .iter()
+                    .any(|base| has_virtual_bases(&base.base))
+                {
+                    let offset = align_to(layout.end, pointer_size);
+                    layout.vbase_pointer = Some(offset);
+                    layout.end = offset + pointer_size;
+                }
+            }
+        }
+    }
+    layout
+}
+
+/// Formats the memory layout of a class: its size and alignment, the offsets
+/// of its vtable pointer, bases and fields, and any padding between them.
+/// LibClang doesn't expose where base subobjects are placed, so their offsets
+/// are worked out from the ABI's layout rules and checked against the fields
+pub fn fmt_layout(class: &Entity, builder: &Builder) -> Option<Html> {
+    let ty = class.get_type()?;
+    let size = ty.get_sizeof().ok()? * 8;
+    let align = ty.get_alignof().ok()?;
+
+    // (offset, size, member) of everything with a known place in the class
+    let mut items: Vec<(usize, usize, Html)> = Vec::new();
+    // fields clang couldn't lay out, like ones of dependent types
+    let mut unknown: Vec<Html> = Vec::new();
+    for field in class
+        .get_children()
+        .iter()
+        .filter(|child| child.get_kind() == EntityKind::FieldDecl)
+    {
+        let field_ty = field.get_type();
+        let member: Html = HtmlList::new(vec![
+            field_ty
+                .map(|t| fmt_type(&t, builder))
+                .unwrap_or(Html::span(&["keyword"], "?")),
+            Html::span(
+                &["name", "space-before"],
+                &field.get_name().unwrap_or("_".into()),
+            ),
+        ])
+        .into();
+        let field_size = match field.get_bit_field_width() {
+            Some(width) => Some(width),
+            None => field_ty
+                .and_then(|t| t.get_sizeof().ok())
+                .map(|size| size * 8),
+        };
+        match (field.get_offset_of_field().ok(), field_size) {
+            (Some(offset), Some(field_size)) => items.push((offset, field_size, member)),
+            _ => unknown.push(member),
+        }
+    }
+    let fields_start = items.iter().map(|i| i.0).min().unwrap_or(size);
+
+    let abi = Abi::of(class);
+    let layout = get_non_virtual_layout(class, abi);
+    // the layout rules are only trusted as far as the fields LibClang placed
+    // agree with them
+    let exact = layout.exact && layout.end <= fields_start;
+    let fmt_base = |base: &Entity, is_virtual: bool| -> Html {
+        HtmlList::new(vec![
+            Html::span(
+                &["keyword", "space-after"],
+                if is_virtual { "virtual base" } else { "base" },
+            ),
+            HtmlElement::new("a")
+                .with_attr_opt("href", base.abs_docs_url(builder.config.clone()))
+                .with_child(Html::span(
+                    &["name"],
+                    &base.get_display_name().unwrap_or("_".into()),
+                ))
+                .into(),
+        ])
+        .into()
+    };
+
+    let pointer_size = class.get_translation_unit().get_target().pointer_width;
+    if let Some(offset) = layout.vtable_pointer {
+        items.push((
+            offset,
+            pointer_size,
+            Html::span(&["keyword"], "vtable pointer"),
+        ));
+    }
+    if let Some(offset) = layout.vbase_pointer {
+        items.push((
+            offset,
+            pointer_size,
+            Html::span(&["keyword"], "virtual base pointer"),
+        ));
+    }
+    for base in &layout.bases {
+        let member = fmt_base(&base.base, false);
+        items.push((
+            base.offset,
+            base.size,
+            if exact {
+                member
+            } else {
+                HtmlList::new(vec![
+                    member,
+                    Html::span(&["badge", "space-before"], "approximate"),
+                ])
+                .into()
+            },
+        ));
+    }
+    items.sort_by_key(|i| i.0);
+
+    let virtual_bases = get_base_definitions(class)
+        .into_iter()
+        .filter(|(is_virtual, _)| *is_virtual)
+        .map(|(_, base)| fmt_base(&base, true))
+        .collect::<Vec<_>>();
+
+    let row = |offset: Option<usize>, size: Option<usize>, member: Html, class: Option<&str>| {
+        HtmlElement::new("tr")
+            .with_class_opt(class)
+            .with_child(
+                HtmlElement::new("td")
+                    .with_text(offset.map(fmt_layout_offset).unwrap_or("?".into())),
+            )
+            .with_child(
+                HtmlElement::new("td").with_text(size.map(fmt_layout_size).unwrap_or("?".into())),
+            )
+            .with_child(HtmlElement::new("td").with_child(member))
+            .into()
+    };
+    let padding = |offset: usize, size: usize| -> Html {
+        row(
+            Some(offset),
+            Some(size),
+            Html::span(&["no-desc"], "padding"),
+            Some("padding"),
+        )
+    };
+
+    let mut rows = vec![HtmlElement::new("tr")
+        .with_child(HtmlElement::new("th").with_text("Offset"))
+        .with_child(HtmlElement::new("th").with_text("Size"))
+        .with_child(HtmlElement::new("th").with_text("Member"))
+        .into()];
+
+    let mut end = 0;
+    for (offset, item_size, member) in items {
+        if offset > end {
+            rows.push(padding(end, offset - end));
+        }
+        end = end.max(offset + item_size);
+        rows.push(row(Some(offset), Some(item_size), member, None));
+    }
+    if !virtual_bases.is_empty() {
+        rows.push(row(
+            Some(end),
+            Some(size.saturating_sub(end)),
+            HtmlElement::new("div").with_children(virtual_bases).into(),
+            None,
+        ));
+    } else if size > end {
+        rows.push(padding(end, size - end));
+    }
+    rows.extend(
+        unknown
+            .into_iter()
+            .map(|member| row(None, None, member, None)),
+    );
+
+    Some(
+        HtmlElement::new("details")
+            .with_classes(&["section", "layout"])
+            .with_child(
+                HtmlElement::new("summary").with_child(
+                    HtmlElement::new("span")
+                        .with_child(Html::feather("chevron-right"))
+                        .with_child(HtmlText::new("Layout"))
+                        .with_child(Html::span(&["badge"], &format!("{} bytes", size / 8))),
+                ),
+            )
+            .with_child(
+                HtmlElement::new("div")
+                    .with_child(Html::p(format!(
+                        "Size: {} bytes, alignment: {align} bytes",
+                        size / 8
+                    )))
+                    .with_child_opt((!exact).then(|| {
+                        Html::p(format!(
+                            "Base offsets are worked out from the {} ABI's layout rules, \
+                            which don't cover everything this class does, so they may be off",
+                            match abi {
+                                Abi::Itanium => "Itanium",
+                                Abi::Msvc => "MSVC",
+                            }
+                        ))
+                    }))
+                    .with_child(HtmlElement::new("table").with_children(rows)),
+            )
+            .into(),
+    )
+}
+
+/// Gets all the classes whose members this class inherits, walking through
+/// every non-private base recursively, closest ones first
+fn get_inherited_bases<'e>(class: &Entity<'e>) -> Vec<Entity<'e>> {
+    let mut bases = vec![*class];
+    let mut i = 0;
+    while let Some(current) = bases.get(i).copied() {
+        for base in current
+            .get_children()
+            .into_iter()
+            .filter(|child| {
+                child.get_kind() == EntityKind::BaseSpecifier
+                    && child.get_accessibility() != Some(Accessibility::Private)
+            })
+            .filter_map(|child| child.get_type()?.get_declaration()?.get_definition())
+        {
+            // virtual and diamond inheritance can reach the same base twice
+            if !bases.iter().any(|b| b.get_usr() == base.get_usr()) {
+                bases.push(base);
+            }
+        }
+        i += 1;
+    }
+    bases.remove(0);
+    bases
+}
+
+/// The members of a class that can be inherited, i.e. everything public or
+/// protected that isn't a constructor or destructor
+fn get_inheritable_members<'e>(class: &Entity<'e>) -> Vec<Entity<'e>> {
+    class
+        .get_children()
+        .into_iter()
+        .filter(|child| {
+            matches!(
+                child.get_accessibility(),
+                Some(Accessibility::Public | Accessibility::Protected)
+            ) && matches!(
+                child.get_function_kind(),
+                EntityKind::Method
+                    | EntityKind::ConversionFunction
+                    | EntityKind::FieldDecl
+                    | EntityKind::VarDecl
+            )
+        })
+        .collect()
+}
+
+/// Identifies a member for figuring out if it's been overridden (or hidden)
+/// by a class closer in the hierarchy
+fn member_key(member: &Entity) -> String {
+    format!(
+        "{}{}",
+        member.get_display_name().unwrap_or_default(),
+        if member.is_const_method() {
+            " const"
+        } else {
+            ""
+        }
+    )
+}
+
+fn fmt_inherited_member(member: &Entity, base: &Entity, builder: &Builder) -> Html {
+    let is_field = matches!(
+        member.get_kind(),
+        EntityKind::FieldDecl | EntityKind::VarDecl
+    );
+    let link = base.abs_docs_url(builder.config.clone()).map(|url| {
+        match (is_field, member_fun_link(member, builder)) {
+            (false, Some(anchor)) => format!("{url}#{anchor}"),
+            _ => url.to_string(),
+        }
+    });
+    HtmlElement::new("details")
+        .with_class("entity-desc")
+        .with_child(if is_field {
+            HtmlElement::new("summary")
+                .with_classes(&["entity", "var"])
+                .with_children(fmt_field_signature(member, builder))
+                .into()
+        } else {
+            fmt_fun_signature(member, builder)
+        })
+        .with_child(
+            HtmlElement::new("div")
+                .with_child(
+                    get_method_doc_comment(member, builder)
+                        .map(|doc| doc.to_html(true))
+                        .unwrap_or(Html::span(&["no-desc"], "No description provided")),
+                )
+                .with_child(
+                    HtmlElement::new("a")
+                        .with_class("inherited-from")
+                        .with_attr_opt("href", link)
+                        .with_text(format!("Defined in {}", member.full_name().join("::"))),
+                ),
+        )
+        .into()
+}
+
+pub fn fmt_inherited_members(class: &Entity, builder: &Builder) -> Html {
+    // whatever a class closer in the hierarchy declares overrides the bases'
+    // members with the same signature, and hides all of the ones with the
+    // same name unless they're brought back with a using declaration
+    let mut seen = HashSet::new();
+    let mut hidden_names = HashSet::new();
+    let declare =
+        |declarer: &Entity, seen: &mut HashSet<String>, hidden_names: &mut HashSet<String>| {
+            let usings = declarer
+                .get_children()
+                .into_iter()
+                .filter(|child| child.get_kind() == EntityKind::UsingDeclaration)
+                .filter_map(|child| child.get_name())
+                .collect::<HashSet<_>>();
+            seen.extend(get_inheritable_members(declarer).iter().map(member_key));
+            // private members hide names just the same
+            hidden_names.extend(
+                declarer
+                    .get_children()
+                    .into_iter()
+                    .filter(|child| {
+                        matches!(
+                            child.get_function_kind(),
+                            EntityKind::Method
+                                | EntityKind::ConversionFunction
+                                | EntityKind::FieldDecl
+                                | EntityKind::VarDecl
+                        )
+                    })
+                    .filter_map(|child| child.get_name())
+                    .filter(|name| !usings.contains(name)),
+            );
+        };
+    declare(class, &mut seen, &mut hidden_names);
+
+    HtmlList::new(
+        get_inherited_bases(class)
+            .into_iter()
+            .filter_map(|base| {
+                let members = get_inheritable_members(&base)
+                    .into_iter()
+                    .filter(|member| {
+                        !member
+                            .get_name()
+                            .is_some_and(|name| hidden_names.contains(&name))
+                            && !seen.contains(&member_key(member))
+                            && !member.is_hidden(&builder.config)
+                    })
+                    .map(|member| fmt_inherited_member(&member, &base, builder))
+                    .collect::<Vec<_>>();
+                declare(&base, &mut seen, &mut hidden_names);
+                (!members.is_empty()).then(|| {
+                    fmt_collapsed_section(
+                        &format!(
+                            "Inherited from {}",
+                            base.get_display_name().unwrap_or("_".into())
+                        ),
+                        members,
+                    )
+                })
+            })
+            .collect(),
+    )
+    .into()
+}
+
+/// The C++ ABI the docs are built for, which decides how vtables are laid out
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Abi {
+    Itanium,
+    Msvc,
+}
+
+impl Abi {
+    fn of(entity: &Entity) -> Self {
+        if entity
+            .get_translation_unit()
+            .get_target()
+            .triple
+            .contains("msvc")
+        {
+            Self::Msvc
+        } else {
+            Self::Itanium
+        }
+    }
+}
+
+struct VtableSlot<'e> {
+    /// The class whose declaration added this slot to the vtable
+    introduced_by: Entity<'e>,
+    /// The final overrider of the slot
+    method: Entity<'e>,
+    /// Which of the destructor's variants this slot is for, if it's a
+    /// destructor
+    variant: Option<&'static str>,
+}
+
+struct Vtable<'e> {
+    /// The base subobject this vtable belongs to, or the class itself for
+    /// the primary vtable
+    base: Entity<'e>,
+    slots: Vec<VtableSlot<'e>>,
+}
+
+/// Identifies a virtual method for matching overrides against the slots they
+/// replace. Destructors override each other regardless of their names
+fn vtable_key(method: &Entity) -> String {
+    if method.get_kind() == EntityKind::Destructor {
+        "~".into()
+    } else {
+        member_key(method)
+    }
+}
+
+/// Whether any class in the hierarchy of this one is inherited virtually
+fn has_virtual_bases(class: &Entity) -> bool {
+    class
+        .get_children()
+        .iter()
+        .filter(|child| child.get_kind() == EntityKind::BaseSpecifier)
+        .any(|child| {
+            child.is_virtual_base()
+                || child
+                    .get_type()
+                    .and_then(|t| t.get_declaration())
+                    .and_then(|d| d.get_definition())
+                    .is_some_and(|base| has_virtual_bases(&base))
+        })
+}
+
+/// Gets the slots a newly introduced virtual method takes up. The Itanium ABI
+/// gives destructors both a complete object and a deleting variant, while MSVC
+/// only has the deleting one
+fn new_vtable_slots<'e>(class: &Entity<'e>, method: Entity<'e>, abi: Abi) -> Vec<VtableSlot<'e>> {
+    let slot = |variant| VtableSlot {
+        introduced_by: *class,
+        method,
+        variant,
+    };
+    match (method.get_kind(), abi) {
+        (EntityKind::Destructor, Abi::Itanium) => {
+            vec![slot(Some("complete")), slot(Some("deleting"))]
+        }
+        (EntityKind::Destructor, Abi::Msvc) => vec![slot(Some("deleting"))],
+        _ => vec![slot(None)],
+    }
+}
+
+/// Gets the vtables of a class, primary one first. The primary vtable extends
+/// the one of the first polymorphic non-virtual base, and every other
+/// polymorphic base brings its own secondary vtables along.
+///
+/// On Itanium, methods that override something only found in a secondary base
+/// also get a slot in the primary vtable. MSVC doesn't do that, but instead
+/// groups overloads together in reverse declaration order
+fn get_vtables<'e>(class: &Entity<'e>, abi: Abi) -> Vec<Vtable<'e>> {
+    let bases = class
+        .get_children()
+        .into_iter()
+        .filter(|child| child.get_kind() == EntityKind::BaseSpecifier)
+        .filter_map(|child| {
+            let base = child.get_type()?.get_declaration()?.get_definition()?;
+            is_polymorphic(&base).then_some((child.is_virtual_base(), base))
+        })
+        .collect::<Vec<_>>();
+    let primary = bases.iter().position(|(is_virtual, _)| !is_virtual);
+
+    let mut vtables = match primary {
+        Some(primary) => {
+            let mut vtables = get_vtables(&bases[primary].1, abi);
+            vtables[0].base = *class;
+            vtables
+        }
+        None => vec![Vtable {
+            base: *class,
+            slots: Vec::new(),
+        }],
+    };
+    for (i, (_, base)) in bases.iter().enumerate() {
+        if Some(i) == primary {
+            continue;
+        }
+        for vtable in get_vtables(base, abi) {
+            // virtual bases are shared, so they only have one set of vtables
+            if !vtables
+                .iter()
+                .any(|v| v.base.get_usr() == vtable.base.get_usr())
+            {
+                vtables.push(vtable);
+            }
+        }
+    }
+
+    // (name, slots) of every method this class adds to the primary vtable
+    let mut new_methods: Vec<(String, Vec<VtableSlot>)> = Vec::new();
+    for method in class
+        .get_children()
+        .into_iter()
+        .filter(|child| child.is_virtual_method())
+    {
+        let key = vtable_key(&method);
+        let mut overrides_primary = false;
+        let mut overrides_secondary = false;
+        for (i, vtable) in vtables.iter_mut().enumerate() {
+            for slot in vtable.slots.iter_mut() {
+                if vtable_key(&slot.method) == key {
+                    slot.method = method;
+                    if i == 0 {
+                        overrides_primary = true;
+                    } else {
+                        overrides_secondary = true;
+                    }
+                }
+            }
+        }
+        if overrides_primary || (overrides_secondary && abi == Abi::Msvc) {
+            continue;
+        }
+        let mut slots = new_vtable_slots(class, method, abi);
+        let name = method.get_name().unwrap_or_default();
+        match new_methods
+            .iter_mut()
+            .find(|(n, _)| abi == Abi::Msvc && *n == name)
+        {
+            Some((_, group)) => {
+                slots.append(group);
+                *group = slots;
+            }
+            None => new_methods.push((name, slots)),
+        }
+    }
+    vtables[0]
+        .slots
+        .extend(new_methods.into_iter().flat_map(|(_, slots)| slots));
+
+    vtables
+}
+
+fn fmt_vtable_slot(index: Option<usize>, slot: &VtableSlot, builder: &Builder) -> Html {
+    let defined_in = slot.method.get_semantic_parent();
+    // hidden methods still take up their slot, they just have no docs to link to
+    let hidden = slot.method.is_hidden(&builder.config);
+    let link = defined_in
+        .filter(|_| !hidden)
+        .and_then(|parent| parent.abs_docs_url(builder.config.clone()))
+        .map(|url| match member_fun_link(&slot.method, builder) {
+            Some(anchor) => format!("{url}#{anchor}"),
+            None => url.to_string(),
+        });
+    let is_override = defined_in.is_some_and(|parent| parent != slot.introduced_by);
+    let summary = (!hidden)
+        .then(|| get_method_doc_comment(&slot.method, builder))
+        .flatten()
+        .and_then(|doc| doc.summary());
+
+    HtmlElement::new("tr")
+        .with_child(
+            HtmlElement::new("td").with_text(index.map(|i| i.to_string()).unwrap_or_default()),
+        )
+        .with_child(
+            HtmlElement::new("td")
+                .with_child(
+                    HtmlElement::new("a")
+                        .with_attr_opt("href", link)
+                        .with_attr_opt("title", summary)
+                        .with_child(Html::span(
+                            &["name"],
+                            &format!(
+                                "{}{}",
+                                slot.method.full_name().join("::"),
+                                slot.method
+                                    .get_display_name()
+                                    .and_then(|name| name.find('(').map(|i| name[i..].to_owned()))
+                                    .unwrap_or_default()
+                            ),
+                        )),
+                )
+                .with_child_opt(
+                    slot.method
+                        .is_const_method()
+                        .then(|| Html::span(&["keyword", "space-before"], "const")),
+                ),
+        )
+        .with_child(
+            HtmlElement::new("td")
+                .with_child_opt(slot.variant.map(|variant| Html::span(&["badge"], variant)))
+                .with_child_opt(hidden.then(|| Html::span(&["badge"], "internal")))
+                .with_child_opt(is_override.then(|| Html::span(&["badge"], "override")))
+                .with_child_opt(
+                    slot.method
+                        .is_pure_virtual_method()
+                        .then(|| Html::span(&["badge", "pure"], "pure virtual")),
+                ),
+        )
+        .into()
+}
+
+/// Formats the vtables of a polymorphic class, with every slot in order and
+/// grouped by the class that introduced it. Virtual inheritance isn't modeled
+/// precisely, so slot indices are left out for classes that use it
+pub fn fmt_vtables(class: &Entity, builder: &Builder) -> Option<Html> {
+    if !is_polymorphic(class) {
+        return None;
+    }
+    let abi = Abi::of(class);
+    let vtables = get_vtables(class, abi);
+    let show_indices = !has_virtual_bases(class);
+
+    let mut rows = Vec::new();
+    for (i, vtable) in vtables.iter().enumerate() {
+        rows.push(
+            HtmlElement::new("tr")
+                .with_class("vtable")
+                .with_child(
+                    HtmlElement::new("th")
+                        .with_attr("colspan", "3")
+                        .with_text(if i == 0 {
+                            "Primary vtable".to_owned()
+                        } else {
+                            format!(
+                                "Secondary vtable for {}",
+                                vtable.base.full_name().join("::")
+                            )
+                        }),
+                )
+                .into(),
+        );
+        let mut introduced_by = None;
+        for (index, slot) in vtable.slots.iter().enumerate() {
+            if introduced_by != Some(slot.introduced_by) {
+                introduced_by = Some(slot.introduced_by);
+                rows.push(
+                    HtmlElement::new("tr")
+                        .with_class("introduced-by")
+                        .with_child(
+                            HtmlElement::new("td").with_attr("colspan", "3").with_child(
+                                HtmlElement::new("a")
+                                    .with_attr_opt(
+                                        "href",
+                                        slot.introduced_by.abs_docs_url(builder.config.clone()),
+                                    )
+                                    .with_text(format!(
+                                        "Introduced by {}",
+                                        slot.introduced_by.full_name().join("::")
+                                    )),
+                            ),
+                        )
+                        .into(),
+                );
+            }
+            rows.push(fmt_vtable_slot(
+                show_indices.then_some(index),
+                slot,
+                builder,
+            ));
+        }
+    }
+
+    let slot_count = vtables.iter().map(|v| v.slots.len()).sum::<usize>();
+    Some(
+        HtmlElement::new("details")
+            .with_classes(&["section", "layout"])
+            .with_child(
+                HtmlElement::new("summary").with_child(
+                    HtmlElement::new("span")
+                        .with_child(Html::feather("chevron-right"))
+                        .with_child(HtmlText::new("Virtual table"))
+                        .with_child(Html::span(&["badge"], &slot_count.to_string())),
+                ),
+            )
+            .with_child(
+                HtmlElement::new("div")
+                    .with_child(Html::p(format!(
+                        "Laid out for the {} ABI{}",
+                        match abi {
+                            Abi::Itanium => "Itanium",
+                            Abi::Msvc => "MSVC",
+                        },
+                        if show_indices {
+                            ""
+                        } else {
+                            ". Slot indices aren't shown since the class uses virtual inheritance"
+                        }
+                    )))
+                    .with_child(HtmlElement::new("table").with_children(rows)),
+            )
+            .into(),
+    )
+}
+
+pub fn fmt_section(title: &str, data: Vec<Html>) -> Html {
+    HtmlElement::new("details")
+        .with_attr("open", "")
+        .with_class("section")
+        .with_child(
+            HtmlElement::new("summary").with_child(
+                HtmlElement::new("span")
+                    .with_child(Html::feather("chevron-right"))
+                    .with_child(HtmlText::new(title))
+                    .with_child(Html::span(&["badge"], &data.len().to_string())),
+            ),
+        )
+        .with_child(HtmlElement::new("div").with_child(HtmlList::new(data)))
+        .into()
+}
+
+/// Same as fmt_section, but closed by default
+pub fn fmt_collapsed_section(title: &str, data: Vec<Html>) -> Html {
+    HtmlElement::new("details")
+        .with_class("section")
+        .with_child(
+            HtmlElement::new("summary").with_child(
+                HtmlElement::new("span")
+                    .with_child(Html::feather("chevron-right"))
+                    .with_child(HtmlText::new(title))
+                    .with_child(Html::span(&["badge"], &data.len().to_string())),
+            ),
+        )
+        .with_child(HtmlElement::new("div").with_child(HtmlList::new(data)))
+        .into()
+}
+
+pub fn fmt_header_link(entity: &Entity, config: Arc<Config>) -> Html {
+    if let Some(link) = entity.github_url(config.clone())
+        && let Some(path) = entity.include_path(config.clone())
+    {
+        let exists_online = entity
+            .config_source(config.clone())
+            .map(|s| s.exists_online)
+            .unwrap_or(true);
+        let disabled = !exists_online;
+        HtmlElement::new("a")
+            .with_attr_opt("href", (!disabled).then_some(link))
+            .with_class("header-link")
+            .with_class_opt(disabled.then_some("disabled"))
+            .with_child(
+                HtmlElement::new("code")
+                    .with_class("header-link")
+                    .with_children(vec![
+                        Html::span(&["keyword"], "#include "),
+                        Html::span(&["url"], &format!("&lt;{}&gt;", path.to_raw_string())),
+                    ]),
+            )
+            .into()
+    } else {
+        Html::p("&lt;Not available online&gt;")
+    }
+}
+
+pub fn fmt_base_classes<'e, T: ASTEntry<'e>>(entry: &T, kw: &str, builder: &Builder) -> Html {
+    let bases = entry
+        .entity()
+        .get_children()
+        .into_iter()
+        .filter(|p| p.get_kind() == EntityKind::BaseSpecifier)
+        .collect::<Vec<_>>();
+
+    HtmlElement::new("div")
+        .with_classes(&["entity", "class"])
+        .with_child_opt(fmt_template_args(entry.entity(), builder))
+        .with_child_opt(fmt_requires_clause(
+            &entry.entity().get_requires_clause(),
+            builder,
+        ))
+        .with_child(
+            HtmlElement::new("span")
+                .with_class("class-decl")
+                .with_child(Html::span(&["keyword", "space-after"], kw))
+                .with_child(Html::span(
+                    &["name"],
+                    entry.entity().get_name().unwrap_or("_".into()).as_str(),
+                ))
+                .with_child_opt(
+                    (!bases.is_empty())
+                        .then_some(Html::span(&["space-before", "space-after"], ":")),
+                )
+                .with_children(
+                    bases
+                        .into_iter()
+                        .map(|base| {
+                            HtmlList::new(
+                                [
+                                    base.get_accessibility().map(|a| {
+                                        Html::span(
+                                            &["keyword", "space-after"],
+                                            match a {
+                                                Accessibility::Public => "public",
+                                                Accessibility::Private => "private",
+                                                Accessibility::Protected => "protected",
+                                            },
+                                        )
+                                    }),
+                                    base.is_virtual_base().then_some(Html::span(
+                                        &["keyword", "space-after"],
+                                        "virtual",
+                                    )),
+                                    base.get_type().map(|ty| fmt_type(&ty, builder)),
+                                ]
+                                .into_iter()
+                                .flatten()
+                                .collect(),
+                            )
+                            .into()
+                        })
+                        .intersperse_with(|| Html::span(&["space-after"], ","))
+                        .collect(),
+                )
+                .with_child(Html::span(&["space-before"], "{ ... }"))
+                .with_child_opt(fmt_deprecated_badge(entry.entity())),
+        )
+        .into()
+}
+
+pub fn fmt_derived_class(entity: &Entity, builder: &Builder) -> Html {
+    HtmlElement::new("div")
+        .with_classes(&["entity", "class"])
+        .with_child(
+            HtmlElement::new("a")
+                .with_attr_opt("href", entity.abs_docs_url(builder.config.clone()))
+                .with_child(Html::span(&["keyword", "space-after"], "class"))
+                .with_child(Html::span(
+                    &["name"],
+                    entity.get_name().unwrap_or("_".into()).as_str(),
+                ))
+                .with_child(Html::span(&["space-before"], "{ ... }")),
+        )
+        .into()
+}
+
+pub fn fmt_member_type(entity: &Entity, builder: &Builder) -> Html {
+    let keyword = match CppItemKind::from(entity) {
+        Some(CppItemKind::Struct) => "struct",
+        Some(CppItemKind::Enum) => "enum",
+        Some(CppItemKind::Alias) => "using",
+        _ => "class",
+    };
+    HtmlElement::new("details")
+        .with_class("entity-desc")
+        .with_child(
+            HtmlElement::new("summary")
+                .with_classes(&["entity", keyword])
+                .with_child_opt(fmt_template_args(entity, builder))
+                .with_child(
+                    HtmlElement::new("a")
+                        .with_attr_opt("href", entity.abs_docs_url(builder.config.clone()))
+                        .with_child(Html::span(&["keyword", "space-after"], keyword))
+                        .with_child(Html::span(
+                            &["name"],
+                            &entity.get_name().unwrap_or("_anon".into()),
+                        )),
+                )
+                .with_child(HtmlText::new(";"))
+                .with_child_opt(fmt_deprecated_badge(entity)),
+        )
+        .with_child(
+            HtmlElement::new("div").with_child(
+                entity
+                    .get_doc_comment()
+                    .map(|s| JSDocComment::parse(s, builder).to_html(true))
+                    .unwrap_or(Html::span(&["no-desc"], "No description provided")),
+            ),
+        )
+        .into()
+}
+
+fn fmt_deprecated_badge(entity: &Entity) -> Option<Html> {
+    entity
+        .get_deprecation()
+        .is_some()
+        .then(|| Html::span(&["deprecated-badge", "space-before"], "deprecated"))
+}
+
+fn fmt_deprecation_message(deprecation: &Deprecation, builder: &Builder) -> Html {
+    HtmlList::new(
+        deprecation
+            .message
+            .clone()
+            .into_iter()
+            .chain(
+                deprecation
+                    .replacement
+                    .as_ref()
+                    .map(|name| format!("Use `{name}` instead.")),
+            )
+            .map(|text| fmt_markdown(builder, &fmt_autolinks(builder, &text), None::<fn(_) -> _>))
+            .collect(),
+    )
+    .into()
+}
+
+pub fn fmt_deprecation_banner(entity: &Entity, builder: &Builder) -> Option<Html> {
+    let deprecation = entity.get_deprecation()?;
+    Some(
+        HtmlElement::new("div")
+            .with_class("deprecated-banner")
+            .with_child(
+                HtmlElement::new("p")
+                    .with_child(Html::feather("alert-triangle"))
+                    .with_child(Html::span(&["title"], "Deprecated")),
+            )
+            .with_child(fmt_deprecation_message(&deprecation, builder))
+            .into(),
+    )
+}
+
+/// Gets the docs URL of a class member or enumerator, which is its parent's
+/// page, pointing to the member where possible
+fn member_docs_url(member: &Entity, builder: &Builder) -> Option<String> {
+    let url = member
+        .get_semantic_parent()?
+        .abs_docs_url(builder.config.clone())?;
+    let anchor = match member.get_kind() {
+        EntityKind::FieldDecl | EntityKind::VarDecl => None,
+        EntityKind::EnumConstantDecl => member.get_name(),
+        _ => member_fun_link(member, builder),
+    };
+    Some(match anchor {
+        Some(anchor) => format!("{url}#{anchor}"),
+        None => url.to_string(),
+    })
+}
+
+/// Gets everything that can be linked to in an item along with links to
+/// them, including class members and enumerators that don't have a page of
+/// their own
+pub fn get_linkable<'e>(
+    item: &CppItem<'e>,
+    builder: &Builder,
+) -> Vec<(Entity<'e>, Option<String>)> {
+    let overloads = item.overloads();
+    let mut res = overloads
+        .iter()
+        .map(|entity| {
+            let url = entity.abs_docs_url(builder.config.clone());
+            (
+                *entity,
+                if overloads.len() > 1 {
+                    url.zip(member_fun_link(entity, builder))
+                        .map(|(url, anchor)| format!("{url}#{anchor}"))
+                } else {
+                    url.map(|url| url.to_string())
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let members = match item {
+        CppItem::Class(_) | CppItem::Struct(_) => item
+            .entity()
+            .get_children()
+            .into_iter()
+            .filter(|child| {
+                matches!(
+                    child.get_function_kind(),
+                    EntityKind::Method
+                        | EntityKind::Constructor
+                        | EntityKind::Destructor
+                        | EntityKind::ConversionFunction
+                        | EntityKind::FieldDecl
+                        | EntityKind::VarDecl
+                ) && child.get_accessibility() != Some(Accessibility::Private)
+            })
+            .collect(),
+        CppItem::Enum(_) => item
+            .entity()
+            .get_children()
+            .into_iter()
+            .filter(|child| child.get_kind() == EntityKind::EnumConstantDecl)
+            .collect(),
+        _ => Vec::new(),
+    };
+    res.extend(
+        members
+            .into_iter()
+            .filter(|member| !member.is_hidden(&builder.config))
+            .map(|member| (member, member_docs_url(&member, builder))),
+    );
+
+    let entries = match item {
+        CppItem::Namespace(ns) => Some(&ns.entries),
+        CppItem::Class(cls) => Some(&cls.entries),
+        CppItem::Struct(cls) => Some(&cls.entries),
+        _ => None,
+    };
+    for entry in entries.into_iter().flat_map(|e| e.values()) {
+        res.extend(get_linkable(entry, builder));
+    }
+    res
+}
+
+/// Lookup table from the names of entities to their docs URLs, built once
+/// per build so that references can be resolved without walking the whole
+/// tree every time
+#[derive(Default)]
+pub struct NameIndex {
+    /// Full names and URLs of everything with a page or an anchor, keyed by
+    /// the last part of their name
+    names: HashMap<String, Vec<(Vec<String>, String)>>,
+    /// URLs of every page, keyed by the unqualified name of the entity
+    entries: HashMap<String, String>,
+    /// URLs of every concept, keyed by its unqualified name
+    concepts: HashMap<String, String>,
+    /// Full names of every namespace, which can be referred to but have no
+    /// page to link to
+    namespaces: HashSet<Vec<String>>,
+}
+
+impl NameIndex {
+    pub fn new(builder: &Builder) -> Self {
+        let mut names: HashMap<_, Vec<_>> = HashMap::new();
+        for (entity, url) in builder
+            .root
+            .all_entries()
+            .flat_map(|entry| get_linkable(entry, builder))
+        {
+            // Namespaces don't have pages of their own
+            if entity.get_kind() == EntityKind::Namespace {
+                continue;
+            }
+            let Some(url) = url else {
+                continue;
+            };
+            let full_name = entity.full_name();
+            if let Some(last) = full_name.last() {
+                names
+                    .entry(last.clone())
+                    .or_default()
+                    .push((full_name, url));
+            }
+        }
+        let mut index = Self {
+            names,
+            ..Default::default()
+        };
+        for entry in builder.root.all_entries() {
+            index.add_entries(entry, builder);
+        }
+        index
+    }
+
+    fn add_entries(&mut self, item: &CppItem, builder: &Builder) {
+        if let CppItem::Namespace(ns) = item {
+            self.namespaces.insert(ns.entity().full_name());
+        }
+        if item.entity().get_kind() != EntityKind::Namespace
+            && let Some(url) = item.entity().abs_docs_url(builder.config.clone())
+        {
+            if matches!(item, CppItem::Concept(_)) {
+                self.concepts.entry(item.name()).or_insert(url.to_string());
+            }
+            self.entries.entry(item.name()).or_insert(url.to_string());
+        }
+        let children = match item {
+            CppItem::Namespace(ns) => Some(&ns.entries),
+            CppItem::Class(cls) => Some(&cls.entries),
+            CppItem::Struct(cls) => Some(&cls.entries),
+            _ => None,
+        };
+        for child in children.into_iter().flat_map(|e| e.values()) {
+            self.add_entries(child, builder);
+        }
+    }
+
+    /// Finds the URL of the concept named `name`, for linking constraints
+    fn find_concept(&self, name: &str) -> Option<&String> {
+        self.concepts.get(name)
+    }
+
+    /// Finds the URL of the page whose name is exactly `name`, for linking
+    /// names that come up in text
+    fn find_entry(&self, name: &str) -> Option<&String> {
+        self.entries.get(name)
+    }
+
+    /// Checks whether `parts` is the end of the full name of a namespace
+    fn has_namespace(&self, parts: &[String]) -> bool {
+        self.namespaces.iter().any(|ns| ns.ends_with(parts))
+    }
+
+    /// Finds the URL of the entity with the shortest full name that ends
+    /// with `parts`
+    fn find(&self, parts: &[String]) -> Option<String> {
+        self.names
+            .get(parts.last()?)?
+            .iter()
+            .filter(|(full_name, _)| full_name.ends_with(parts))
+            .min_by_key(|(full_name, _)| full_name.len())
+            .map(|(_, url)| url.clone())
+    }
+}
+
+/// Strips the parameter list off a function name like `foo()` or
+/// `operator()(int)`, taking care not to strip the parentheses of the call
+/// operator itself
+fn strip_params(name: &str) -> &str {
+    let Some(stripped) = name.strip_suffix(')') else {
+        return name;
+    };
+    let mut depth = 1;
+    for (i, c) in stripped.char_indices().rev() {
+        match c {
+            ')' => depth += 1,
+            '(' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            let rest = &name[..i];
+            return if rest.trim_end().ends_with("operator") {
+                name
+            } else {
+                rest
+            };
+        }
+    }
+    name
+}
+
+/// Splits a name like `Foo::bar()` or `geode::utils::string` into its parts,
+/// without any function parameters
+fn name_parts(name: &str) -> Option<Vec<String>> {
+    let name = strip_params(name.trim()).trim().trim_start_matches("::");
+    (!name.is_empty()).then(|| {
+        name.split("::")
+            .map(|part| part.trim().to_owned())
+            .collect()
+    })
+}
+
+/// Finds the docs URL of an entity from its name, like `Foo::bar` or
+/// `geode::utils::string`. Names don't have to be fully qualified, in which
+/// case the match with the shortest full name wins. Functions may also be
+/// referred to with their parentheses, like `foo()`. Namespaces don't have
+/// pages, so they never resolve, see names_namespace
+pub fn resolve_name(builder: &Builder, name: &str) -> Option<String> {
+    builder.names().find(&name_parts(name)?)
+}
+
+/// Checks whether a name refers to a namespace, which is a valid reference
+/// even though there's no page to link it to
+pub fn names_namespace(builder: &Builder, name: &str) -> bool {
+    name_parts(name).is_some_and(|parts| builder.names().has_namespace(&parts))
+}
+
+/// Gets everything deprecated in the whole project, sorted by full name
+pub fn get_all_deprecated<'e>(builder: &Builder<'e>) -> Vec<(String, Entity<'e>, Option<String>)> {
+    let mut deprecated = builder
+        .root
+        .all_entries()
+        .flat_map(|entry| get_linkable(entry, builder))
+        .filter(|(entity, _)| entity.get_deprecation().is_some())
+        .map(|(entity, url)| (entity.full_name().join("::"), entity, url))
+        .collect::<Vec<_>>();
+    deprecated.sort_by(|a, b| a.0.cmp(&b.0));
+    deprecated
+}
+
+pub fn output_deprecated(builder: &Builder) -> Vec<(&'static str, Html)> {
+    let deprecated = get_all_deprecated(builder);
+
+    vec![
+        ("name", HtmlText::new("Deprecated API").into()),
+        (
+            "entries",
+            fmt_section(
+                "Deprecated",
+                deprecated
+                    .into_iter()
+                    .map(|(name, entity, url)| {
+                        HtmlElement::new("div")
+                            .with_class("deprecated-entry")
+                            .with_child(
+                                HtmlElement::new("a")
+                                    .with_attr_opt("href", url)
+                                    .with_child(Html::span(&["name"], &name)),
+                            )
+                            .with_child_opt(
+                                entity
+                                    .get_deprecation()
+                                    .map(|d| fmt_deprecation_message(&d, builder)),
+                            )
+                            .into()
+                    })
+                    .collect(),
+            ),
+        ),
+    ]
+}
+
+pub fn output_entity<'e, T: ASTEntry<'e>>(
+    entry: &T,
+    builder: &Builder,
+) -> Vec<(&'static str, Html)> {
+    output_entity_with_description(
+        entry,
+        builder,
+        HtmlList::new(
+            fmt_deprecation_banner(entry.entity(), builder)
+                .into_iter()
+                .chain([get_method_doc_comment(entry.entity(), builder)
+                    .map(|c| c.to_html(false))
+                    .unwrap_or(Html::span(&["no-desc"], "No description provided"))])
+                .collect(),
+        )
+        .into(),
+    )
+}
+
+fn output_entity_with_description<'e, T: ASTEntry<'e>>(
+    entry: &T,
+    builder: &Builder,
+    description: Html,
+) -> Vec<(&'static str, Html)> {
+    vec![
+        ("name", HtmlText::new(entry.name()).into()),
+        ("description", description),
+        (
+            "header_link",
+            fmt_header_link(entry.entity(), builder.config.clone()),
+        ),
+        (
+            "examples",
+            fmt_section(
+                "Examples",
+                entry
+                    .entity()
+                    .get_doc_comment()
+                    .map(|s| {
+                        JSDocComment::parse(s, builder)
+                            .examples()
+                            .iter()
+                            .map(|example| example.to_html())
+                            .collect()
+                    })
+                    .unwrap_or(Vec::new()),
+            ),
+        ),
+    ]
+}
+
+/// A region of a class body that puts the members in it into a group, from
+/// either `// MARK: - Name` or a Doxygen `@name Name` followed by `@{`
+struct GroupRegion {
+    /// None for a plain `// MARK: -` separator that ends the previous group
+    name: Option<String>,
+    description: Option<String>,
+    start: usize,
+    end: Option<usize>,
+}
+
+static MARK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"//[ \t]*MARK:[ \t]*(?:-[ \t]*)?([^\r\n]*)").unwrap());
+static GROUP_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[@\\]name[ \t]+([^\r\n]*)").unwrap());
+static GROUP_OPEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[@\\]\{").unwrap());
+static GROUP_OPEN_COMMENT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?://[/!]?|/\*[*!]?)[ \t]*[@\\]\{").unwrap());
+static GROUP_CLOSE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[@\\]\}").unwrap());
+
+/// Gets where the comment containing the text at `at` ends. Line comments go
+/// on for as long as the following lines are comments too
+fn comment_block_end(body: &str, at: usize) -> usize {
+    let line_start = body[..at].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    if !body[line_start..at].trim_start().starts_with("//") {
+        return body[at..]
+            .find("*/")
+            .map(|i| at + i + 2)
+            .unwrap_or(body.len());
+    }
+    let mut end = at;
+    loop {
+        end = body[end..]
+            .find('\n')
+            .map(|i| end + i)
+            .unwrap_or(body.len());
+        if !body[end..].trim_start().starts_with("//") {
+            return end;
+        }
+        end += 1;
+    }
+}
+
+/// Finds the regions that group members in the source of a class
+fn get_group_regions(class: &Entity) -> Vec<GroupRegion> {
+    let Some(range) = class.get_range() else {
+        return Vec::new();
+    };
+    let (start, end) = (
+        range.get_start().get_file_location(),
+        range.get_end().get_file_location(),
+    );
+    let Some(contents) = start.file.and_then(|f| get_file_contents(&f)) else {
+        return Vec::new();
+    };
+    let Some(body) = contents.get(start.offset as usize..end.offset as usize) else {
+        return Vec::new();
+    };
+    let offset = start.offset as usize;
+
+    let mut regions = MARK
+        .captures_iter(body)
+        .map(|caps| {
+            let name = caps[1].trim();
+            GroupRegion {
+                name: (!name.is_empty()).then(|| name.to_owned()),
+                description: None,
+                // Marks go on until the next one or the end of the class
+                start: offset + caps.get(0).unwrap().start(),
+                end: None,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for caps in GROUP_NAME.captures_iter(body) {
+        let name_end = caps.get(0).unwrap().end();
+        let comment_end = comment_block_end(body, caps.get(0).unwrap().start()).max(name_end);
+        // The `@{` has to be in the same comment as the name, or start the
+        // comment right after it
+        let open = match GROUP_OPEN.find_at(&body[..comment_end], name_end) {
+            Some(open) => open,
+            None => {
+                let next = comment_end + body[comment_end..].len()
+                    - body[comment_end..].trim_start().len();
+                match GROUP_OPEN_COMMENT
+                    .find_at(body, next)
+                    .filter(|open| open.start() == next)
+                {
+                    Some(open) => open,
+                    None => continue,
+                }
+            }
+        };
+        // Anything between the name and the `@{` describes the group
+        let description = body[name_end..open.start().min(comment_end)]
+            .lines()
+            .map(|line| line.trim().trim_matches(['*', '/', '!']).trim())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_owned();
+        regions.push(GroupRegion {
+            name: Some(caps[1].trim().trim_end_matches("*/").trim().to_owned()),
+            description: (!description.is_empty()).then_some(description),
+            start: offset + caps.get(0).unwrap().start(),
+            end: GROUP_CLOSE
+                .find_at(body, open.end())
+                .map(|close| offset + close.start()),
+        });
+    }
+    regions
+}
+
+/// A group of class members, specified with `@group Name` in their doc
+/// comments or with regions in the class body
+struct MemberGroup<'e> {
+    name: String,
+    description: Option<String>,
+    members: Vec<Entity<'e>>,
+}
+
+/// Groups the members that are in a group, in source order
+fn get_member_groups<'e>(
+    members: Vec<Entity<'e>>,
+    regions: &[GroupRegion],
+) -> Vec<MemberGroup<'e>> {
+    let offset_of = |member: &Entity| {
+        member
+            .get_range()
+            .map(|r| r.get_start().get_file_location().offset as usize)
+    };
+    let mut members = members
+        .into_iter()
+        .filter_map(|member| Some((offset_of(&member)?, member)))
+        .collect::<Vec<_>>();
+    members.sort_by_key(|(offset, _)| *offset);
+
+    let mut groups: Vec<MemberGroup<'e>> = Vec::new();
+    for (offset, member) in members {
+        let name = match member
+            .get_doc_comment()
+            .and_then(|raw| get_comment_group(&raw))
+        {
+            Some(name) => name,
+            None => {
+                // The innermost region this member is in
+                let Some(name) = regions
+                    .iter()
+                    .filter(|r| r.start <= offset && r.end.is_none_or(|end| offset < end))
+                    .max_by_key(|r| r.start)
+                    .and_then(|r| r.name.clone())
+                else {
+                    continue;
+                };
+                name
+            }
+        };
+        match groups.iter_mut().find(|g| g.name == name) {
+            Some(group) => group.members.push(member),
+            None => groups.push(MemberGroup {
+                description: regions
+                    .iter()
+                    .find(|r| r.name.as_ref() == Some(&name))
+                    .and_then(|r| r.description.clone()),
+                name,
+                members: vec![member],
+            }),
+        }
+    }
+    groups
+}
+
+/// Same as fmt_section, but with a description before the members
+fn fmt_group_section(group: &MemberGroup, builder: &Builder) -> Html {
+    HtmlElement::new("details")
+        .with_attr("open", "")
+        .with_classes(&["section", "member-group"])
         .with_child(
-            HtmlElement::new("a")
-                .with_attr_opt("href", entity.abs_docs_url(builder.config.clone()))
-                .with_child(Html::span(&["keyword", "space-after"], "class"))
-                .with_child(Html::span(
-                    &["name"],
-                    entity.get_name().unwrap_or("_".into()).as_str(),
-                ))
-                .with_child(Html::span(&["space-before"], "{ ... }")),
+            HtmlElement::new("summary").with_child(
+                HtmlElement::new("span")
+                    .with_child(Html::feather("chevron-right"))
+                    .with_child(HtmlText::new(&group.name))
+                    .with_child(Html::span(&["badge"], &group.members.len().to_string())),
+            ),
+        )
+        .with_child(
+            HtmlElement::new("div")
+                .with_child_opt(group.description.as_ref().map(|desc| {
+                    HtmlElement::new("div")
+                        .with_class("group-description")
+                        .with_child(fmt_markdown(
+                            builder,
+                            &fmt_autolinks(builder, desc),
+                            None::<fn(_) -> _>,
+                        ))
+                }))
+                .with_children(
+                    group
+                        .members
+                        .iter()
+                        .map(|member| match member.get_kind() {
+                            EntityKind::FieldDecl | EntityKind::VarDecl => {
+                                fmt_field(member, builder)
+                            }
+                            _ => fmt_class_method(member, builder),
+                        })
+                        .collect(),
+                ),
         )
         .into()
 }
 
-pub fn output_entity<'e, T: ASTEntry<'e>>(
+pub fn output_classlike<'e, T: ASTEntry<'e>>(
     entry: &T,
+    member_types: &HashMap<String, CppItem<'e>>,
     builder: &Builder,
 ) -> Vec<(&'static str, Html)> {
-    vec![
-        ("name", HtmlText::new(entry.name()).into()),
+    let mut ent = output_entity(entry, builder);
+    // only list the member types that get pages of their own
+    let member_types = member_types
+        .values()
+        .map(|item| *item.entity())
+        .collect::<HashSet<_>>();
+
+    // Members in a group are shown in the group instead of by their kind
+    let groupable = [
+        (Access::Public, Include::Statics),
+        (Access::Public, Include::Members),
+        (Access::Protected, Include::Statics),
+        (Access::Protected, Include::Members),
+    ]
+    .into_iter()
+    .flat_map(|(access, include)| entry.entity().get_member_functions(access, include))
+    .chain(entry.entity().get_children().into_iter().filter(|child| {
+        matches!(
+            child.get_kind(),
+            EntityKind::FieldDecl | EntityKind::VarDecl
+        ) && matches!(
+            child.get_accessibility(),
+            Some(Accessibility::Public | Accessibility::Protected)
+        )
+    }))
+    .filter(|e| !e.is_hidden(&builder.config))
+    .collect::<Vec<_>>();
+    let groups = get_member_groups(groupable, &get_group_regions(entry.entity()));
+    let grouped = groups
+        .iter()
+        .flat_map(|g| g.members.iter().copied())
+        .collect::<HashSet<_>>();
+    let ungrouped = |e: &Entity| !grouped.contains(e);
+
+    ent.extend(vec![
         (
-            "description",
-            entry
-                .entity()
-                .get_comment()
-                .map(|s| JSDocComment::parse(s, builder).to_html(false))
-                .unwrap_or(Html::span(&["no-desc"], "No description provided")),
+            "base_classes",
+            fmt_base_classes(entry, entry.category(), builder),
         ),
         (
-            "header_link",
-            fmt_header_link(entry.entity(), builder.config.clone()),
+            "member_types",
+            fmt_section(
+                "Member types",
+                entry
+                    .entity()
+                    .get_children()
+                    .iter()
+                    .filter(|child| member_types.contains(child))
+                    .map(|e| fmt_member_type(e, builder))
+                    .collect::<Vec<_>>(),
+            ),
         ),
         (
-            "examples",
+            "constructors",
             fmt_section(
-                "Examples",
+                "Constructors",
                 entry
                     .entity()
-                    .get_comment()
-                    .map(|s| {
-                        JSDocComment::parse(s, builder)
-                            .examples()
-                            .iter()
-                            .map(|example| example.to_html())
-                            .collect()
-                    })
-                    .unwrap_or(Vec::new()),
+                    .get_special_members(EntityKind::Constructor, Access::Public)
+                    .into_iter()
+                    .filter(|e| !e.is_hidden(&builder.config))
+                    .map(|e| fmt_class_method(&e, builder))
+                    .collect::<Vec<_>>(),
             ),
         ),
-    ]
-}
-
-pub fn output_classlike<'e, T: ASTEntry<'e>>(
-    entry: &T,
-    builder: &Builder,
-) -> Vec<(&'static str, Html)> {
-    let mut ent = output_entity(entry, builder);
-    ent.extend(vec![
         (
-            "base_classes",
-            fmt_base_classes(entry, entry.category(), builder),
+            "destructor",
+            fmt_section(
+                "Destructor",
+                entry
+                    .entity()
+                    .get_special_members(EntityKind::Destructor, Access::All)
+                    .into_iter()
+                    .filter(|e| !e.is_hidden(&builder.config))
+                    .map(|e| fmt_class_method(&e, builder))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+        (
+            "conversion_operators",
+            fmt_section(
+                "Conversion operators",
+                entry
+                    .entity()
+                    .get_special_members(EntityKind::ConversionFunction, Access::Public)
+                    .into_iter()
+                    .filter(|e| !e.is_hidden(&builder.config))
+                    .map(|e| fmt_class_method(&e, builder))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+        (
+            "member_groups",
+            HtmlList::new(
+                groups
+                    .iter()
+                    .map(|group| fmt_group_section(group, builder))
+                    .collect(),
+            )
+            .into(),
         ),
         (
             "public_static_functions",
@@ -489,6 +2874,7 @@ pub fn output_classlike<'e, T: ASTEntry<'e>>(
                     .entity()
                     .get_member_functions(Access::Public, Include::Statics)
                     .into_iter()
+                    .filter(|e| !e.is_hidden(&builder.config) && ungrouped(e))
                     .map(|e| fmt_class_method(&e, builder))
                     .collect::<Vec<_>>(),
             ),
@@ -501,6 +2887,33 @@ pub fn output_classlike<'e, T: ASTEntry<'e>>(
                     .entity()
                     .get_member_functions(Access::Public, Include::Members)
                     .into_iter()
+                    .filter(|e| !e.is_hidden(&builder.config) && ungrouped(e))
+                    .map(|e| fmt_class_method(&e, builder))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+        (
+            "protected_constructors",
+            fmt_section(
+                "Protected constructors",
+                entry
+                    .entity()
+                    .get_special_members(EntityKind::Constructor, Access::Protected)
+                    .into_iter()
+                    .filter(|e| !e.is_hidden(&builder.config))
+                    .map(|e| fmt_class_method(&e, builder))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+        (
+            "protected_static_functions",
+            fmt_section(
+                "Protected static methods",
+                entry
+                    .entity()
+                    .get_member_functions(Access::Protected, Include::Statics)
+                    .into_iter()
+                    .filter(|e| !e.is_hidden(&builder.config) && ungrouped(e))
                     .map(|e| fmt_class_method(&e, builder))
                     .collect::<Vec<_>>(),
             ),
@@ -514,10 +2927,47 @@ pub fn output_classlike<'e, T: ASTEntry<'e>>(
                     .entity()
                     .get_member_functions(Access::Protected, Include::Members)
                     .into_iter()
+                    .filter(|e| !e.is_hidden(&builder.config) && ungrouped(e))
                     .map(|e| fmt_class_method(&e, builder))
                     .collect::<Vec<_>>(),
             ),
         ),
+        (
+            "static_members",
+            fmt_section(
+                "Static members",
+                entry
+                    .entity()
+                    .get_children()
+                    .iter()
+                    .filter(|child| {
+                        child.get_kind() == EntityKind::VarDecl
+                            && child.get_accessibility() == Some(Accessibility::Public)
+                            && !child.is_hidden(&builder.config)
+                            && ungrouped(child)
+                    })
+                    .map(|e| fmt_field(e, builder))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+        (
+            "protected_static_members",
+            fmt_section(
+                "Protected static members",
+                entry
+                    .entity()
+                    .get_children()
+                    .iter()
+                    .filter(|child| {
+                        child.get_kind() == EntityKind::VarDecl
+                            && child.get_accessibility() == Some(Accessibility::Protected)
+                            && !child.is_hidden(&builder.config)
+                            && ungrouped(child)
+                    })
+                    .map(|e| fmt_field(e, builder))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
         (
             "public_members",
             fmt_section(
@@ -529,6 +2979,8 @@ pub fn output_classlike<'e, T: ASTEntry<'e>>(
                     .filter(|child| {
                         child.get_kind() == EntityKind::FieldDecl
                             && child.get_accessibility() == Some(Accessibility::Public)
+                            && !child.is_hidden(&builder.config)
+                            && ungrouped(child)
                     })
                     .map(|e| fmt_field(e, builder))
                     .collect::<Vec<_>>(),
@@ -545,11 +2997,37 @@ pub fn output_classlike<'e, T: ASTEntry<'e>>(
                     .filter(|child| {
                         child.get_kind() == EntityKind::FieldDecl
                             && child.get_accessibility() == Some(Accessibility::Protected)
+                            && !child.is_hidden(&builder.config)
+                            && ungrouped(child)
                     })
                     .map(|e| fmt_field(e, builder))
                     .collect::<Vec<_>>(),
             ),
         ),
+        (
+            "layout",
+            builder
+                .config
+                .analysis
+                .show_layout
+                .then(|| fmt_layout(entry.entity(), builder))
+                .flatten()
+                .unwrap_or(Html::p("")),
+        ),
+        (
+            "vtable",
+            builder
+                .config
+                .analysis
+                .show_vtable
+                .then(|| fmt_vtables(entry.entity(), builder))
+                .flatten()
+                .unwrap_or(Html::p("")),
+        ),
+        (
+            "inherited_members",
+            fmt_inherited_members(entry.entity(), builder),
+        ),
         (
             "derived_classes",
             fmt_section("Derived classes", {
@@ -584,44 +3062,207 @@ pub fn output_classlike<'e, T: ASTEntry<'e>>(
 pub fn output_function<'e, T: ASTEntry<'e>>(
     entry: &T,
     builder: &Builder,
+) -> Vec<(&'static str, Html)> {
+    let overloads = entry.overloads();
+    if overloads.len() <= 1 {
+        let mut ent = output_entity(entry, builder);
+        ent.push((
+            "function_signature",
+            fmt_fun_signature(entry.entity(), builder),
+        ));
+        return ent;
+    }
+
+    // every overload carries its own description
+    let mut ent = output_entity_with_description(
+        entry,
+        builder,
+        HtmlList::new(
+            fmt_deprecation_banner(entry.entity(), builder)
+                .into_iter()
+                .chain([Html::p(format!(
+                    "{} has {} overloads, each of which is documented below.",
+                    entry.name(),
+                    overloads.len()
+                ))])
+                .collect(),
+        )
+        .into(),
+    );
+    ent.push((
+        "function_signature",
+        HtmlElement::new("div")
+            .with_class("overloads")
+            .with_children(
+                overloads
+                    .iter()
+                    .map(|fun| fmt_fun_decl(fun, member_fun_link(fun, builder), builder))
+                    .collect(),
+            )
+            .into(),
+    ));
+    ent
+}
+
+pub fn output_enum<'e, T: ASTEntry<'e>>(entry: &T, builder: &Builder) -> Vec<(&'static str, Html)> {
+    let signed = entry
+        .entity()
+        .get_enum_underlying_type()
+        .is_some_and(|ty| ty.get_canonical_type().is_signed_integer());
+    let mut ent = output_entity(entry, builder);
+    ent.extend(vec![
+        ("enum_decl", fmt_enum_decl(entry.entity(), builder)),
+        (
+            "enumerators",
+            fmt_section(
+                "Enumerators",
+                entry
+                    .entity()
+                    .get_children()
+                    .iter()
+                    .filter(|child| {
+                        child.get_kind() == EntityKind::EnumConstantDecl
+                            && !child.is_hidden(&builder.config)
+                    })
+                    .map(|e| fmt_enumerator(e, signed, builder))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+    ]);
+    ent
+}
+
+pub fn output_alias<'e, T: ASTEntry<'e>>(
+    entry: &T,
+    builder: &Builder,
 ) -> Vec<(&'static str, Html)> {
     let mut ent = output_entity(entry, builder);
     ent.extend(vec![(
-        "function_signature",
-        fmt_fun_signature(entry.entity(), builder),
+        "alias_decl",
+        fmt_alias_decl(entry.entity(), builder),
+    )]);
+    ent
+}
+
+pub fn output_concept<'e, T: ASTEntry<'e>>(
+    entry: &T,
+    builder: &Builder,
+) -> Vec<(&'static str, Html)> {
+    let mut ent = output_entity(entry, builder);
+    ent.extend(vec![(
+        "concept_decl",
+        fmt_concept_decl(entry.entity(), builder),
+    )]);
+    ent
+}
+
+pub fn output_variable<'e, T: ASTEntry<'e>>(
+    entry: &T,
+    builder: &Builder,
+) -> Vec<(&'static str, Html)> {
+    let mut ent = output_entity(entry, builder);
+    ent.extend(vec![(
+        "variable_decl",
+        fmt_variable_decl(entry.entity(), builder),
     )]);
     ent
 }
 
-fn fmt_autolinks_recursive(
-    entity: &CppItem,
-    config: Arc<Config>,
-    annotations: &mut Annotations<'_>,
-) {
-    annotations.rewind();
+pub fn output_macro<'e, T: ASTEntry<'e>>(
+    entry: &T,
+    builder: &Builder,
+) -> Vec<(&'static str, Html)> {
+    let mut ent = output_entity(entry, builder);
+    ent.extend(vec![
+        ("macro_decl", fmt_macro_decl(entry.entity())),
+        ("macro_replacement", fmt_macro_replacement(entry.entity())),
+    ]);
+    ent
+}
+
+fn fmt_plain_autolinks(builder: &Builder, text: &str) -> String {
+    let mut annotations = Annotations::new(text);
     while let Some(word) = annotations.next() {
         // skip stuff that have all-lowercase names (so words like "get"
         // and "data" don't get autolinked)
-        if !word.chars().all(|c| c.is_lowercase()) && *word == entity.name() {
-            if let Some(url) = entity.entity().abs_docs_url(config.clone()) {
-                annotations.annotate(format!("[{word}]({})", url));
-            }
+        if !word.chars().all(|c| c.is_lowercase())
+            && let Some(url) = builder.names().find_entry(&word)
+        {
+            annotations.annotate(format!("[{word}]({url})"));
         }
     }
+    annotations.into_result()
+}
 
-    if let CppItem::Namespace(ns) = entity {
-        for v in ns.entries.values() {
-            fmt_autolinks_recursive(v, config.clone(), annotations);
-        }
+/// Formats an explicit reference to an entity as a markdown link
+fn fmt_reference(builder: &Builder, target: &str, text: Option<&str>) -> String {
+    let text = text
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(String::from)
+        .unwrap_or(format!("`{target}`"));
+    // unresolved references are warned about once per build instead, see
+    // check_doc_comments
+    match resolve_name(builder, target) {
+        Some(url) => format!("[{text}]({url})"),
+        None => text,
     }
 }
 
+static REFERENCES: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"\{@link\s+([^\s}|]+)\s*\|?([^}]*)\}",
+        r#"|[@\\]ref\s+((?:[\w:~]+::)?operator\s*(?:\(\)|\[\]|[^\s\w(]+)(?:\(\))?|[\w:~]+(?:\(\))?)(?:\s+"([^"]*)")?"#,
+        r"|(?:\[([^\[\]]+)\])?\[`([^`]+)`\]",
+    ))
+    .unwrap()
+});
+
+/// Finds the explicit references in text, as (whole match, target, label)
+fn find_references(text: &str) -> impl Iterator<Item = (Match<'_>, Match<'_>, Option<Match<'_>>)> {
+    REFERENCES.captures_iter(text).filter_map(|caps| {
+        let whole = caps.get(0).unwrap();
+        // [`Name`](url) is a regular markdown link
+        if caps.get(6).is_some() && text[whole.end()..].starts_with('(') {
+            return None;
+        }
+        Some(if let Some(target) = caps.get(1) {
+            (whole, target, caps.get(2))
+        } else if let Some(target) = caps.get(3) {
+            (whole, target, caps.get(4))
+        } else {
+            (whole, caps.get(6).unwrap(), caps.get(5))
+        })
+    })
+}
+
+/// Gets the explicit references in text that don't resolve to anything
+pub fn unresolved_links(builder: &Builder, text: &str) -> Vec<String> {
+    find_references(text)
+        .map(|(_, target, _)| target.as_str())
+        .filter(|target| resolve_name(builder, target).is_none())
+        .map(String::from)
+        .collect()
+}
+
+/// Adds links to the names of entities in text. Besides names that are
+/// linked automatically, links can be made explicitly with `{@link Name}`,
+/// `{@link Name text}`, `@ref Name "text"`, [`Name`] and [text][`Name`], where
+/// the names are resolved with resolve_name
 pub fn fmt_autolinks(builder: &Builder, text: &str) -> String {
-    let mut annotations = Annotations::new(text);
-    for entry in builder.root.entries.values() {
-        fmt_autolinks_recursive(entry, builder.config.clone(), &mut annotations);
+    let mut res = String::new();
+    let mut last = 0;
+    for (whole, target, label) in find_references(text) {
+        res.push_str(&fmt_plain_autolinks(builder, &text[last..whole.start()]));
+        res.push_str(&fmt_reference(
+            builder,
+            target.as_str(),
+            label.map(|l| l.as_str()),
+        ));
+        last = whole.end();
     }
-    annotations.into_result()
+    res.push_str(&fmt_plain_autolinks(builder, &text[last..]));
+    res
 }
 
 pub fn fmt_emoji(text: &CowStr) -> String {
@@ -666,6 +3307,167 @@ pub fn fmt_emoji(text: &CowStr) -> String {
     res
 }
 
-pub fn member_fun_link(entity: &Entity) -> Option<String> {
-    entity.get_name()
+/// Gets every overload a function is documented alongside of. For free
+/// functions that's every overload merged into its page, even across
+/// reopened namespaces, and for members every member with the same name
+fn get_overload_set<'e>(entity: &Entity<'e>, builder: &Builder<'e>) -> Vec<Entity<'e>> {
+    let name = entity.get_name();
+    let parent = entity.get_semantic_parent();
+    if parent.is_some_and(|p| {
+        matches!(
+            p.get_kind(),
+            EntityKind::Namespace | EntityKind::TranslationUnit
+        )
+    }) {
+        let full_name = entity.full_name();
+        let mut ns = &builder.root;
+        for part in &full_name[..full_name.len().saturating_sub(1)] {
+            match ns.entries.get(part) {
+                Some(CppItem::Namespace(inner)) => ns = inner,
+                _ => return vec![*entity],
+            }
+        }
+        if let Some(fun @ CppItem::Function(_)) = full_name.last().and_then(|n| ns.entries.get(n)) {
+            return fun.overloads();
+        }
+        return vec![*entity];
+    }
+    parent
+        .map(|parent| {
+            parent
+                .get_children()
+                .into_iter()
+                .filter(|c| c.get_name() == name)
+                .collect()
+        })
+        .unwrap_or(vec![*entity])
+}
+
+/// Anchor of a function on the page it's documented on. Overloads are told
+/// apart by their position among every overload on that page
+pub fn member_fun_link(entity: &Entity, builder: &Builder) -> Option<String> {
+    let name = entity.get_name()?;
+    let index = get_overload_set(entity, builder)
+        .iter()
+        .position(|o| o.get_usr() == entity.get_usr())
+        .unwrap_or(0);
+    Some(overload_link(&name, index))
+}
+
+/// Anchor of the `index`th overload of a function, the first one being just
+/// the plain name
+pub fn overload_link(name: &str, index: usize) -> String {
+    if index == 0 {
+        name.to_owned()
+    } else {
+        format!("{name}-{}", index + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(name: &str) -> Vec<String> {
+        name.split("::").map(String::from).collect()
+    }
+
+    #[test]
+    fn params_are_stripped() {
+        assert_eq!(strip_params("foo"), "foo");
+        assert_eq!(strip_params("foo()"), "foo");
+        assert_eq!(strip_params("Foo::bar(int, float)"), "Foo::bar");
+        assert_eq!(strip_params("foo(std::function<void(int)>)"), "foo");
+        assert_eq!(strip_params("operator()"), "operator()");
+        assert_eq!(strip_params("Foo::operator ()"), "Foo::operator ()");
+        assert_eq!(strip_params("operator()(int)"), "operator()");
+    }
+
+    #[test]
+    fn names_are_split() {
+        assert_eq!(name_parts("Foo::bar()"), Some(parts("Foo::bar")));
+        assert_eq!(name_parts(" ::geode::utils "), Some(parts("geode::utils")));
+        assert_eq!(
+            name_parts("Foo::operator()(int)"),
+            Some(parts("Foo::operator()"))
+        );
+        assert_eq!(name_parts("()"), None);
+        assert_eq!(name_parts(""), None);
+    }
+
+    #[test]
+    fn shortest_name_wins() {
+        let index = NameIndex {
+            names: HashMap::from([(
+                "bar".to_owned(),
+                vec![
+                    (
+                        parts("geode::Foo::bar"),
+                        "/classes/geode/Foo#bar".to_owned(),
+                    ),
+                    (parts("Foo::bar"), "/classes/Foo#bar".to_owned()),
+                    (
+                        parts("geode::Baz::bar"),
+                        "/classes/geode/Baz#bar".to_owned(),
+                    ),
+                ],
+            )]),
+            namespaces: HashSet::from([parts("geode::utils")]),
+            ..Default::default()
+        };
+        assert_eq!(
+            index.find(&parts("bar")).as_deref(),
+            Some("/classes/Foo#bar")
+        );
+        assert_eq!(
+            index.find(&parts("Foo::bar")).as_deref(),
+            Some("/classes/Foo#bar")
+        );
+        assert_eq!(
+            index.find(&parts("Baz::bar")).as_deref(),
+            Some("/classes/geode/Baz#bar")
+        );
+        assert_eq!(index.find(&parts("Qux::bar")), None);
+        assert_eq!(index.find(&parts("baz")), None);
+        assert!(index.has_namespace(&parts("utils")));
+        assert!(index.has_namespace(&parts("geode::utils")));
+        assert!(!index.has_namespace(&parts("geode")));
+    }
+
+    /// Finds the references in text as (target, label)
+    fn refs(text: &str) -> Vec<(&str, Option<&str>)> {
+        find_references(text)
+            .map(|(_, target, label)| (target.as_str(), label.map(|l| l.as_str())))
+            .collect()
+    }
+
+    #[test]
+    fn javadoc_references() {
+        assert_eq!(refs("See {@link Foo::bar}."), vec![("Foo::bar", Some(""))]);
+        assert_eq!(refs("{@link Foo the foo}"), vec![("Foo", Some("the foo"))]);
+        assert_eq!(refs("{@link Foo|the foo}"), vec![("Foo", Some("the foo"))]);
+    }
+
+    #[test]
+    fn doxygen_references() {
+        assert_eq!(refs("See @ref Foo::bar()."), vec![("Foo::bar()", None)]);
+        assert_eq!(
+            refs(r#"@ref Foo "the foo""#),
+            vec![("Foo", Some("the foo"))]
+        );
+        assert_eq!(refs(r"\ref Foo::~Foo"), vec![("Foo::~Foo", None)]);
+        assert_eq!(refs("@ref operator== and"), vec![("operator==", None)]);
+        assert_eq!(
+            refs("@ref Foo::operator()"),
+            vec![("Foo::operator()", None)]
+        );
+    }
+
+    #[test]
+    fn markdown_references() {
+        assert_eq!(refs("The [`Foo`] class"), vec![("Foo", None)]);
+        assert_eq!(refs("[the foo][`Foo`]"), vec![("Foo", Some("the foo"))]);
+        assert_eq!(refs("[`Foo`](https://example.com)"), vec![]);
+        assert_eq!(refs("Just `Foo` and [Foo]"), vec![]);
+    }
 }
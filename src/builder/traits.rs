@@ -1,7 +1,11 @@
-use clang::{Accessibility, Entity, EntityKind};
+use clang::{
+    source::{File, SourceRange},
+    token::{Token, TokenKind},
+    Accessibility, Availability, Entity, EntityKind,
+};
 use serde_json::json;
 
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc, sync::Arc};
 
 use tokio::task::JoinHandle;
 
@@ -11,7 +15,48 @@ use crate::{
     url::UrlPath,
 };
 
-use super::{builder::Builder, namespace::CppItemKind};
+use super::{
+    builder::Builder,
+    comment::{is_hidden_comment, strip_group_opening, Deprecation},
+    namespace::CppItemKind,
+};
+
+thread_local! {
+    /// Contents of every source file read so far in this build, since
+    /// comments that LibClang doesn't attach are read from the source
+    static FILE_CONTENTS: RefCell<HashMap<PathBuf, Option<Rc<str>>>> = RefCell::default();
+
+    /// Deprecations found so far, since every page that links to an entity
+    /// checks whether it's deprecated
+    static DEPRECATIONS: RefCell<HashMap<EntityKey, Option<Deprecation>>> = RefCell::default();
+
+    /// Whether the doc comments of the entities checked so far hide them,
+    /// since every list of members filters out the hidden ones
+    static HIDDEN: RefCell<HashMap<EntityKey, bool>> = RefCell::default();
+}
+
+/// Identifies an entity across translation units by where it's declared,
+/// since entities themselves can't outlive their translation unit
+type EntityKey = (PathBuf, u32, EntityKind);
+
+fn entity_key(entity: &Entity) -> Option<EntityKey> {
+    let location = entity.get_location()?.get_file_location();
+    Some((
+        location.file?.get_path(),
+        location.offset,
+        entity.get_kind(),
+    ))
+}
+
+/// Gets the contents of a source file, reading it only once per build
+pub fn get_file_contents(file: &File) -> Option<Rc<str>> {
+    FILE_CONTENTS.with_borrow_mut(|cache| {
+        cache
+            .entry(file.get_path())
+            .or_insert_with(|| file.get_contents().map(Rc::from))
+            .clone()
+    })
+}
 
 pub trait EntityMethods<'e> {
     /// Get the config source for this entity
@@ -45,6 +90,38 @@ pub trait EntityMethods<'e> {
     fn get_member_functions(&self, visibility: Access, include_statics: Include)
         -> Vec<Entity<'e>>;
 
+    /// Gets the constructors, destructors or conversion functions from this
+    /// entity, assuming it is a class-like entity
+    fn get_special_members(&self, kind: EntityKind, visibility: Access) -> Vec<Entity<'e>>;
+
+    /// Gets the kind of this function, looking through function templates
+    fn get_function_kind(&self) -> EntityKind;
+
+    /// Gets the tokens of this function's declaration that come after its
+    /// parameter list, like qualifiers, `noexcept` or a trailing return type,
+    /// up to its body
+    fn get_trailing_tokens(&self) -> Vec<Token<'e>>;
+
+    /// Reads what LibClang doesn't expose from the tokens after this
+    /// function's parameter list
+    fn get_function_tail(&self) -> FunctionTail;
+
+    /// Gets the source of the default value of this parameter, template
+    /// parameter or field, i.e. whatever comes after its `=`, or its braced
+    /// initializer
+    fn get_default_value(&self) -> Option<String>;
+
+    /// Checks if this template parameter is a parameter pack
+    fn is_parameter_pack(&self) -> bool;
+
+    /// Checks if this entity is a concept definition. LibClang doesn't have a
+    /// cursor kind for these, so they have to be recognized from their tokens
+    fn is_concept(&self) -> bool;
+
+    /// Gets the constraint expression of this template's `requires` clause,
+    /// either the leading or the trailing one
+    fn get_requires_clause(&self) -> Vec<Token<'e>>;
+
     /// Gets the function arguments for this method, including templated ones
     fn get_function_arguments(&self) -> Option<Vec<Entity<'e>>>;
 
@@ -52,11 +129,35 @@ pub trait EntityMethods<'e> {
     /// This might have to read a file from disk, and also includes whitespace and all
     fn extract_source_string(&self) -> Option<String>;
 
-    /// Same as extract_source_string, but removes new lines, double spaces, and leading/trailing whitespace
-    fn extract_source_string_cleaned(&self) -> Option<String>;
-
     /// Checks if the entitiy is in one of the allowed external libraries
     fn get_allowed_external_lib(&self, config: Arc<Config>) -> Option<Arc<ExternalLib>>;
+
+    /// Gets the keywords in this entity's declaration that come before its
+    /// name, like `static`, `inline` or `constexpr`. LibClang doesn't expose
+    /// most of these so they have to be read from the source tokens
+    fn get_leading_keywords(&self) -> Vec<String>;
+
+    /// Gets the documentation comment for this entity. Same as get_comment,
+    /// except that it also finds comments that LibClang doesn't attach to
+    /// entities, like the ones before macro definitions
+    fn get_doc_comment(&self) -> Option<String>;
+
+    /// Reads the doc comment directly preceding the line this entity starts on
+    /// from the source file
+    fn get_preceding_comment(&self) -> Option<String>;
+
+    /// Reads a trailing doc comment on the same line after this entity, like
+    /// `int x; ///< The x position` or `Value = 1, /**< Some value */`
+    fn get_trailing_comment(&self) -> Option<String>;
+
+    /// Checks if this entity has been deprecated, either with an attribute or
+    /// with `@deprecated` in its doc comment
+    fn get_deprecation(&self) -> Option<Deprecation>;
+
+    /// Checks if this entity has been hidden from the docs with `@internal`,
+    /// `@private` or `@hidden` in its doc comment, unless the docs are being
+    /// built with those included
+    fn is_hidden(&self, config: &Config) -> bool;
 }
 
 impl<'e> EntityMethods<'e> for Entity<'e> {
@@ -187,29 +288,253 @@ impl<'e> EntityMethods<'e> for Entity<'e> {
             .into_iter()
             .filter(|child| {
                 (child.get_kind() == EntityKind::Method
-                    || child.get_kind() == EntityKind::FunctionTemplate)
+                    || child.get_kind() == EntityKind::FunctionTemplate
+                        && child.get_function_kind() == EntityKind::Method)
                     && match include_statics {
                         Include::Members => !child.is_static_method(),
                         Include::Statics => child.is_static_method(),
                         Include::All => true,
                     }
-                    && match child.get_accessibility() {
-                        Some(Accessibility::Protected) => {
-                            matches!(visibility, Access::All | Access::Protected)
-                        }
-                        Some(Accessibility::Public) => {
-                            matches!(visibility, Access::All | Access::Public)
-                        }
-                        _ => false,
+                    && is_visible(child, &visibility)
+            })
+            .collect()
+    }
+
+    fn get_special_members(&self, kind: EntityKind, visibility: Access) -> Vec<Entity<'e>> {
+        self.get_children()
+            .into_iter()
+            .filter(|child| child.get_function_kind() == kind && is_visible(child, &visibility))
+            .collect()
+    }
+
+    fn get_function_kind(&self) -> EntityKind {
+        match self.get_kind() {
+            EntityKind::FunctionTemplate => {
+                self.get_template_kind().unwrap_or(EntityKind::FunctionDecl)
+            }
+            kind => kind,
+        }
+    }
+
+    fn get_trailing_tokens(&self) -> Vec<Token<'e>> {
+        let Some(range) = self.get_range() else {
+            return Vec::new();
+        };
+        let name_offset = self.get_location().map(|l| l.get_file_location().offset);
+        // `operator()` has an extra pair of parentheses in its name
+        let name_len = if self.get_name().is_some_and(|n| n == "operator()") {
+            3
+        } else {
+            1
+        };
+
+        let mut param_depth = 0;
+        let mut params_closed = false;
+        let mut depth = 0;
+        range
+            .tokenize()
+            .into_iter()
+            .skip_while(|token| {
+                Some(token.get_location().get_file_location().offset) != name_offset
+            })
+            .skip(name_len)
+            .skip_while(|token| {
+                if params_closed {
+                    return false;
+                }
+                match token.get_spelling().as_str() {
+                    "(" => param_depth += 1,
+                    ")" => {
+                        param_depth -= 1;
+                        params_closed = param_depth == 0;
                     }
+                    _ => {}
+                }
+                true
+            })
+            .take_while(|token| {
+                let spelling = token.get_spelling();
+                if depth == 0 && matches!(spelling.as_str(), "{" | ";" | ":" | "try") {
+                    return false;
+                }
+                match spelling.as_str() {
+                    "(" | "[" | "{" => depth += 1,
+                    ")" | "]" | "}" => depth -= 1,
+                    _ => {}
+                }
+                true
             })
             .collect()
     }
 
+    fn get_function_tail(&self) -> FunctionTail {
+        let tokens = self.get_trailing_tokens();
+        let spellings = tokens.iter().map(|t| t.get_spelling()).collect::<Vec<_>>();
+        let mut tail = FunctionTail {
+            deleted: spellings.ends_with(&["=".into(), "delete".into()]),
+            trailing_return: false,
+            noexcept: None,
+        };
+
+        // the exception specification and trailing return type both come
+        // right after the declarator, before any requires clause, virt
+        // specifier or `= delete`
+        let mut depth = 0;
+        let mut noexcept_start = None;
+        for (i, spelling) in spellings.iter().enumerate() {
+            match spelling.as_str() {
+                "noexcept" if depth == 0 => {
+                    noexcept_start = spellings.get(i + 1).filter(|s| *s == "(").map(|_| i + 1);
+                }
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => {
+                    depth -= 1;
+                    if depth == 0
+                        && let Some(start) = noexcept_start.take()
+                    {
+                        tail.noexcept = tokens_source(&tokens[start..=i]);
+                    }
+                }
+                "->" if depth == 0 => {
+                    tail.trailing_return = true;
+                    break;
+                }
+                "requires" | "override" | "final" | "=" if depth == 0 => break,
+                _ => {}
+            }
+        }
+        tail
+    }
+
+    fn get_default_value(&self) -> Option<String> {
+        let range = self.get_range()?;
+        let end = range.get_end().get_file_location().offset;
+        let tokens = range.tokenize();
+
+        let name_offset = self.get_location().map(|l| l.get_file_location().offset);
+
+        // anything before the `=` is a declaration, so angle brackets can
+        // only be template arguments there
+        let mut depth = 0;
+        let eq = tokens.iter().position(|token| {
+            let offset = token.get_location().get_file_location().offset;
+            match token.get_spelling().as_str() {
+                "{" if depth == 0 && Some(offset) > name_offset => return true,
+                "(" | "[" | "{" | "<" => depth += 1,
+                ")" | "]" | "}" | ">" => depth -= 1,
+                ">>" => depth -= 2,
+                "=" if depth == 0 => return true,
+                _ => {}
+            }
+            false
+        })?;
+        // braced initializers are kept whole
+        let start = if tokens[eq].get_spelling() == "{" {
+            eq
+        } else {
+            eq + 1
+        };
+        // older LibClang versions include the token right after the range too
+        let value = tokens[start..]
+            .iter()
+            .take_while(|token| token.get_range().get_start().get_file_location().offset < end)
+            .copied()
+            .collect::<Vec<_>>();
+        tokens_source(&value)
+    }
+
+    fn is_parameter_pack(&self) -> bool {
+        let Some(range) = self.get_range() else {
+            return false;
+        };
+        let name_offset = self.get_location().map(|l| l.get_file_location().offset);
+        range
+            .tokenize()
+            .into_iter()
+            .take_while(|token| {
+                Some(token.get_location().get_file_location().offset) != name_offset
+                    && token.get_spelling() != "="
+            })
+            .any(|token| token.get_spelling() == "...")
+    }
+
+    fn is_concept(&self) -> bool {
+        if !matches!(
+            self.get_kind(),
+            EntityKind::NotImplemented | EntityKind::UnexposedDecl
+        ) {
+            return false;
+        }
+        // CppItemKind::from checks this all the time, so look for the keyword
+        // in the source before the name instead of tokenizing it
+        let (Some(range), Some(name)) = (self.get_range(), self.get_location()) else {
+            return false;
+        };
+        let start = range.get_start().get_file_location();
+        let name = name.get_file_location();
+        start
+            .file
+            .and_then(|file| get_file_contents(&file))
+            .is_some_and(|contents| {
+                contents
+                    .get(start.offset as usize..name.offset as usize)
+                    .is_some_and(|head| {
+                        head.split(|c: char| !c.is_alphanumeric() && c != '_')
+                            .any(|word| word == "concept")
+                    })
+            })
+    }
+
+    fn get_requires_clause(&self) -> Vec<Token<'e>> {
+        let Some(range) = self.get_range() else {
+            return Vec::new();
+        };
+        // Only the head of the declaration can have a requires clause, so
+        // don't tokenize bodies
+        let body = self.get_children().into_iter().find(|child| {
+            child.get_kind() == EntityKind::CompoundStmt
+                || (child.is_declaration()
+                    && !matches!(
+                        child.get_kind(),
+                        EntityKind::TemplateTypeParameter
+                            | EntityKind::NonTypeTemplateParameter
+                            | EntityKind::TemplateTemplateParameter
+                            | EntityKind::ParmDecl
+                    ))
+        });
+        let head = match body.and_then(|body| body.get_range()) {
+            Some(body) => SourceRange::new(range.get_start(), body.get_start()),
+            None => range,
+        };
+        let tokens = head.tokenize();
+
+        // requires-expressions in bodies are always nested in some brackets
+        let mut depth = 0;
+        let Some(start) = tokens.iter().position(|token| {
+            match token.get_spelling().as_str() {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth -= 1,
+                "requires" if depth == 0 => return true,
+                _ => {}
+            }
+            false
+        }) else {
+            return Vec::new();
+        };
+
+        let tokens = &tokens[start + 1..];
+        tokens[..constraint_len(tokens)].to_vec()
+    }
+
     fn get_function_arguments(&self) -> Option<Vec<Entity<'e>>> {
         if !matches!(
             self.get_kind(),
-            EntityKind::FunctionTemplate | EntityKind::FunctionDecl | EntityKind::Method
+            EntityKind::FunctionTemplate
+                | EntityKind::FunctionDecl
+                | EntityKind::Method
+                | EntityKind::Constructor
+                | EntityKind::Destructor
+                | EntityKind::ConversionFunction
         ) {
             return None;
         }
@@ -227,26 +552,12 @@ impl<'e> EntityMethods<'e> for Entity<'e> {
         let range = self.get_range()?;
         let start = range.get_start().get_file_location();
         let end = range.get_end().get_file_location();
-        let contents = start.file?.get_contents()?;
+        let contents = get_file_contents(&start.file?)?;
         contents
             .get(start.offset as usize..end.offset as usize)
             .map(|s| s.into())
     }
 
-    fn extract_source_string_cleaned(&self) -> Option<String> {
-        // TODO: this code is stupid and should be improved
-        Some(
-            self.extract_source_string()?
-                .trim()
-                .replace('\t', " ")
-                .replace(['\r', '\n'], "")
-                .split(' ')
-                .filter(|x| !x.is_empty())
-                .intersperse(" ")
-                .collect(),
-        )
-    }
-
     fn get_allowed_external_lib(&self, config: Arc<Config>) -> Option<Arc<ExternalLib>> {
         self.is_in_system_header()
             .then(|| self.get_location())
@@ -262,15 +573,190 @@ impl<'e> EntityMethods<'e> for Entity<'e> {
             })
             .cloned()
     }
+
+    fn get_leading_keywords(&self) -> Vec<String> {
+        let Some(range) = self.get_range() else {
+            return Vec::new();
+        };
+        let name_offset = self.get_location().map(|l| l.get_file_location().offset);
+        range
+            .tokenize()
+            .into_iter()
+            .take_while(|token| {
+                Some(token.get_location().get_file_location().offset) != name_offset
+            })
+            .filter(|token| token.get_kind() == TokenKind::Keyword)
+            .map(|token| token.get_spelling())
+            .collect()
+    }
+
+    fn get_doc_comment(&self) -> Option<String> {
+        if self.get_kind() == EntityKind::MacroDefinition {
+            return self.get_preceding_comment();
+        }
+        let comment = self.get_comment().and_then(strip_group_opening);
+        match self.get_kind() {
+            // only these can be followed by a trailing `///<` comment
+            EntityKind::FieldDecl
+            | EntityKind::EnumConstantDecl
+            | EntityKind::ParmDecl
+            | EntityKind::VarDecl => comment.or_else(|| self.get_trailing_comment()),
+            _ => comment,
+        }
+    }
+
+    fn get_preceding_comment(&self) -> Option<String> {
+        let start = self.get_range()?.get_start().get_file_location();
+        let contents = get_file_contents(&start.file?)?;
+        let line_start = contents
+            .get(..start.offset as usize)?
+            .rfind('\n')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+
+        // The comment must end on the line right before this entity
+        let before = contents[..line_start]
+            .strip_suffix('\n')?
+            .trim_end_matches(['\r', ' ', '\t']);
+
+        if before.ends_with("*/") {
+            let comment = &before[before.rfind("/*")?..];
+            (comment.starts_with("/**") || comment.starts_with("/*!")).then(|| comment.to_owned())
+        } else {
+            let mut lines = before
+                .lines()
+                .rev()
+                .map(|line| line.trim())
+                .take_while(|line| line.starts_with("///") || line.starts_with("//!"))
+                .collect::<Vec<_>>();
+            lines.reverse();
+            (!lines.is_empty()).then(|| lines.join("\n"))
+        }
+    }
+
+    fn get_trailing_comment(&self) -> Option<String> {
+        let end = self.get_range()?.get_end().get_file_location();
+        let contents = get_file_contents(&end.file?)?;
+
+        // Only separators may come between the entity and its comment, so
+        // that a comment after `int a; int b; ///< B` only documents b
+        let after = contents
+            .get(end.offset as usize..)?
+            .trim_start_matches([' ', '\t', ',', ';']);
+
+        if after.starts_with("///<") || after.starts_with("//!<") {
+            // Following lines that are also trailing comments continue it
+            let lines = after
+                .lines()
+                .map(|line| line.trim())
+                .enumerate()
+                .take_while(|(i, line)| {
+                    *i == 0 || line.starts_with("///<") || line.starts_with("//!<")
+                })
+                .map(|(_, line)| line)
+                .collect::<Vec<_>>();
+            Some(lines.join("\n"))
+        } else if after.starts_with("/**<") || after.starts_with("/*!<") {
+            Some(after[..after.find("*/")? + 2].to_owned())
+        } else {
+            None
+        }
+    }
+
+    fn get_deprecation(&self) -> Option<Deprecation> {
+        let Some(key) = entity_key(self) else {
+            return find_deprecation(self);
+        };
+        if let Some(cached) = DEPRECATIONS.with_borrow(|cache| cache.get(&key).cloned()) {
+            return cached;
+        }
+        let deprecation = find_deprecation(self);
+        DEPRECATIONS.with_borrow_mut(|cache| cache.insert(key, deprecation.clone()));
+        deprecation
+    }
+
+    fn is_hidden(&self, config: &Config) -> bool {
+        if config.include_internal {
+            return false;
+        }
+        let find_hidden = || {
+            self.get_doc_comment()
+                .is_some_and(|c| is_hidden_comment(&c))
+        };
+        let Some(key) = entity_key(self) else {
+            return find_hidden();
+        };
+        if let Some(hidden) = HIDDEN.with_borrow(|cache| cache.get(&key).copied()) {
+            return hidden;
+        }
+        let hidden = find_hidden();
+        HIDDEN.with_borrow_mut(|cache| cache.insert(key, hidden));
+        hidden
+    }
+}
+
+/// Reads the deprecation of an entity from its attributes and doc comment
+fn find_deprecation(entity: &Entity) -> Option<Deprecation> {
+    let from_comment = entity
+        .get_doc_comment()
+        .and_then(|c| Deprecation::from_comment(&c));
+    if entity.get_availability() != Availability::Deprecated {
+        return from_comment;
+    }
+
+    // LibClang doesn't expose the attribute's arguments, so read the
+    // message (and the replacement, for the GNU version) from its tokens
+    let literals = entity
+        .get_children()
+        .into_iter()
+        .filter(|child| child.get_kind() == EntityKind::UnexposedAttr)
+        .filter_map(|attr| attr.get_range())
+        .map(|range| range.tokenize())
+        .find(|tokens| {
+            tokens
+                .first()
+                .is_some_and(|t| t.get_spelling().trim_matches('_') == "deprecated")
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|t| t.get_kind() == TokenKind::Literal)
+        .filter_map(|t| {
+            let spelling = t.get_spelling();
+            let start = spelling.find('"')? + 1;
+            let end = spelling.rfind('"')?;
+            (start < end).then(|| spelling[start..end].to_owned())
+        })
+        .collect::<Vec<_>>();
+
+    let from_comment = from_comment.unwrap_or(Deprecation {
+        message: None,
+        replacement: None,
+    });
+    Some(Deprecation {
+        message: from_comment.message.or(literals.first().cloned()),
+        replacement: from_comment.replacement.or(literals.get(1).cloned()),
+    })
+}
+
+/// The parts of a function's declaration after its parameter list that
+/// LibClang doesn't expose
+pub struct FunctionTail {
+    /// Whether the function has been deleted with `= delete`
+    pub deleted: bool,
+    /// Whether the return type is declared after the parameters
+    pub trailing_return: bool,
+    /// The expression in `noexcept(...)`, parentheses included
+    pub noexcept: Option<String>,
 }
 
 #[derive(Clone)]
 pub struct SubItem {
     pub title: String,
+    pub deprecated: bool,
 }
 
 impl SubItem {
-    pub fn for_classlike(entity: &Entity) -> Vec<SubItem> {
+    pub fn for_classlike(entity: &Entity, config: &Config) -> Vec<SubItem> {
         let Some(kind) = CppItemKind::from(entity) else {
             return Vec::new();
         };
@@ -278,14 +764,22 @@ impl SubItem {
             CppItemKind::Class | CppItemKind::Struct => entity
                 .get_member_functions(Access::All, Include::All)
                 .into_iter()
+                .filter(|e| !e.is_hidden(config))
                 .filter_map(|e| {
                     Some(SubItem {
                         title: e.get_name()?,
+                        deprecated: e.get_deprecation().is_some(),
                     })
                 })
                 .collect(),
 
-            CppItemKind::Namespace | CppItemKind::Function => Vec::new(),
+            CppItemKind::Namespace
+            | CppItemKind::Enum
+            | CppItemKind::Alias
+            | CppItemKind::Concept
+            | CppItemKind::Function
+            | CppItemKind::Variable
+            | CppItemKind::Macro => Vec::new(),
         }
     }
 }
@@ -293,7 +787,14 @@ impl SubItem {
 pub enum NavItem {
     Root(Option<String>, Vec<NavItem>),
     Dir(String, Vec<NavItem>, Option<(String, bool)>, bool),
-    Link(String, UrlPath, Option<(String, bool)>, Vec<SubItem>),
+    Link(
+        String,
+        UrlPath,
+        Option<(String, bool)>,
+        Vec<SubItem>,
+        Vec<NavItem>,
+        bool,
+    ),
 }
 
 impl NavItem {
@@ -308,9 +809,38 @@ impl NavItem {
             url,
             icon.map(|s| (s.0.into(), s.1)),
             suboptions,
+            Vec::new(),
+            false,
         )
     }
 
+    /// Create a link that also contains other items, like a class with
+    /// nested types
+    pub fn new_link_with_items(
+        name: &str,
+        url: UrlPath,
+        icon: Option<(&str, bool)>,
+        suboptions: Vec<SubItem>,
+        items: Vec<NavItem>,
+    ) -> NavItem {
+        NavItem::Link(
+            name.into(),
+            url,
+            icon.map(|s| (s.0.into(), s.1)),
+            suboptions,
+            items,
+            false,
+        )
+    }
+
+    /// Marks a link as deprecated, which strikes it through in the navigation
+    pub fn deprecated(mut self, deprecated: bool) -> NavItem {
+        if let NavItem::Link(_, _, _, _, _, ref mut value) = self {
+            *value = deprecated;
+        }
+        self
+    }
+
     pub fn new_dir(name: &str, items: Vec<NavItem>, icon: Option<(&str, bool)>) -> NavItem {
         NavItem::Dir(name.into(), items, icon.map(|s| (s.0.into(), s.1)), false)
     }
@@ -328,17 +858,27 @@ impl NavItem {
         NavItem::Root(name.map(|s| s.into()), items)
     }
 
-    pub fn suboptions_titles(&self, config: Arc<Config>) -> HashMap<String, usize> {
+    /// Gets the full names of all the suboptions, along with how many other
+    /// overloads they have and whether all of them are deprecated
+    pub fn suboptions_titles(&self, config: Arc<Config>) -> HashMap<String, (usize, bool)> {
         match self {
-            NavItem::Link(name, _, _, suboptions) => {
+            NavItem::Link(name, _, _, suboptions, items, _) => {
                 let mut res = HashMap::new();
-                for opt in suboptions.iter().map(|o| format!("{}::{}", name, o.title)) {
-                    if let Some(r) = res.get_mut(&opt) {
-                        *r += 1;
+                for opt in suboptions {
+                    let title = format!("{}::{}", name, opt.title);
+                    if let Some((count, deprecated)) = res.get_mut(&title) {
+                        *count += 1;
+                        *deprecated &= opt.deprecated;
                     } else {
-                        res.insert(opt, 0);
+                        res.insert(title, (0, opt.deprecated));
                     }
                 }
+                res.extend(
+                    items
+                        .iter()
+                        .flat_map(|i| i.suboptions_titles(config.clone()))
+                        .map(|(t, count)| (format!("{}::{}", name, t), count)),
+                );
                 res
             }
 
@@ -357,12 +897,14 @@ impl NavItem {
 
     pub fn to_json(&self, config: Arc<Config>) -> serde_json::Value {
         match self {
-            NavItem::Link(name, url, icon, _) => {
+            NavItem::Link(name, url, icon, _, items, deprecated) => {
                 json!({
                     "type": "link",
                     "icon": icon,
                     "name": name,
                     "url": url.to_absolute(config.clone()).to_string(),
+                    "deprecated": deprecated,
+                    "items": items.iter().map(|x| x.to_json(config.clone())).collect::<Vec<_>>()
                 })
             }
 
@@ -404,6 +946,11 @@ pub trait OutputEntry<'e>: Entry<'e> {
 pub trait ASTEntry<'e>: Entry<'e> {
     fn entity(&self) -> &Entity<'e>;
     fn category(&self) -> &'static str;
+    /// All the declarations documented by this entry. Only functions can
+    /// have more than one
+    fn overloads(&self) -> Vec<Entity<'e>> {
+        vec![*self.entity()]
+    }
     fn output_description(&self, builder: &'e Builder<'e>) -> String {
         format!(
             "Documentation for the {} {} in {}",
@@ -414,6 +961,91 @@ pub trait ASTEntry<'e>: Entry<'e> {
     }
 }
 
+/// Skips past a balanced pair of brackets starting at `i`
+fn skip_brackets(tokens: &[Token], mut i: usize, open: &str, close: &str) -> usize {
+    let mut depth = 0;
+    while let Some(token) = tokens.get(i) {
+        let spelling = token.get_spelling();
+        if spelling == open {
+            depth += 1;
+        } else if spelling == close {
+            depth -= 1;
+        } else if close == ">" && spelling == ">>" {
+            depth -= 2;
+        }
+        i += 1;
+        if depth <= 0 {
+            break;
+        }
+    }
+    i
+}
+
+/// Gets how many tokens the constraint expression at the start of these
+/// tokens spans. A requires clause can only consist of primary expressions
+/// joined with `&&` and `||`, which is what lets us know where it ends
+fn constraint_len(tokens: &[Token]) -> usize {
+    let mut i = 0;
+    loop {
+        match tokens.get(i).map(|t| t.get_spelling()).as_deref() {
+            Some("(") => i = skip_brackets(tokens, i, "(", ")"),
+            Some("requires") => {
+                i += 1;
+                if tokens.get(i).is_some_and(|t| t.get_spelling() == "(") {
+                    i = skip_brackets(tokens, i, "(", ")");
+                }
+                i = skip_brackets(tokens, i, "{", "}");
+            }
+            Some(_) => {
+                // a possibly qualified name, maybe with template arguments
+                while tokens.get(i).is_some_and(|t| {
+                    t.get_kind() == TokenKind::Identifier || t.get_spelling() == "::"
+                }) {
+                    i += 1;
+                }
+                if tokens.get(i).is_some_and(|t| t.get_spelling() == "<") {
+                    i = skip_brackets(tokens, i, "<", ">");
+                } else if i == 0 {
+                    // `true`, `false` and other literals
+                    i += 1;
+                }
+            }
+            None => break,
+        }
+        if tokens
+            .get(i)
+            .is_some_and(|t| matches!(t.get_spelling().as_str(), "&&" | "||"))
+        {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    i.min(tokens.len())
+}
+
+/// Gets the source code spanning these tokens, with whitespace collapsed
+fn tokens_source(tokens: &[Token]) -> Option<String> {
+    let start = tokens.first()?.get_range().get_start().get_file_location();
+    let end = tokens.last()?.get_range().get_end().get_file_location();
+    let contents = get_file_contents(&start.file?)?;
+    Some(
+        contents
+            .get(start.offset as usize..end.offset as usize)?
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+fn is_visible(entity: &Entity, visibility: &Access) -> bool {
+    match entity.get_accessibility() {
+        Some(Accessibility::Protected) => matches!(visibility, Access::All | Access::Protected),
+        Some(Accessibility::Public) => matches!(visibility, Access::All | Access::Public),
+        _ => false,
+    }
+}
+
 pub enum Access {
     All,
     Public,
@@ -163,6 +163,8 @@ decl_config! {
         },
         analysis {
             compile_args: Vec<String> = Vec::new(),
+            show_layout: bool = false,
+            show_vtable: bool = false,
         },
         cmake? {
             config_args: Vec<String> = Vec::new(),
@@ -174,10 +176,17 @@ decl_config! {
         templates {
             class:          Arc<String> as parse_template = default_template!("../templates/class.html"),
             struct_:        Arc<String> as parse_template = default_template!("../templates/struct.html"),
+            enum_:          Arc<String> as parse_template = default_template!("../templates/enum.html"),
+            alias:          Arc<String> as parse_template = default_template!("../templates/alias.html"),
+            concept:        Arc<String> as parse_template = default_template!("../templates/concept.html"),
             function:       Arc<String> as parse_template = default_template!("../templates/function.html"),
+            variable:       Arc<String> as parse_template = default_template!("../templates/variable.html"),
+            macro_:         Arc<String> as parse_template = default_template!("../templates/macro.html"),
             head:           Arc<String> as parse_template = default_template!("../templates/head.html"),
             nav:            Arc<String> as parse_template = default_template!("../templates/nav.html"),
             file:           Arc<String> as parse_template = default_template!("../templates/file.html"),
+            deprecated:     Arc<String> as parse_template = default_template!("../templates/deprecated.html"),
+            coverage:       Arc<String> as parse_template = default_template!("../templates/coverage.html"),
             page:           Arc<String> as parse_template = default_template!("../templates/page.html"),
             tutorial:       Arc<String> as parse_template = default_template!("../templates/tutorial.html"),
             tutorial_index: Arc<String> as parse_template = default_template!("../templates/tutorial-index.html"),
@@ -194,6 +203,8 @@ decl_config! {
         let input_dir: PathBuf,
         let output_dir: PathBuf,
         let output_url: Option<UrlPath>,
+        let include_internal: bool,
+        let min_coverage: Option<f64>,
     }
 }
 
@@ -202,6 +213,8 @@ impl Config {
         input_dir: PathBuf,
         output_dir: PathBuf,
         output_url: Option<UrlPath>,
+        include_internal: bool,
+        min_coverage: Option<f64>,
     ) -> Result<Arc<Config>, String> {
         let mut config: Config = toml::from_str(
             &fs::read_to_string(input_dir.join("flash.toml"))
@@ -212,6 +225,8 @@ impl Config {
         config.input_dir = input_dir;
         config.output_dir = output_dir;
         config.output_url = output_url;
+        config.include_internal = include_internal;
+        config.min_coverage = min_coverage;
         Ok(Arc::from(config))
     }
 
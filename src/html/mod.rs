@@ -24,10 +24,6 @@ impl Html {
         HtmlElement::new("p").with_text(text).into()
     }
 
-    pub fn div<T: AsRef<str>>(text: T) -> Html {
-        HtmlElement::new("div").with_text(text).into()
-    }
-
     pub fn span(classes: &[&str], text: &str) -> Html {
         HtmlElement::new("span")
             .with_classes(classes)
@@ -171,7 +167,7 @@ impl GenHtml for HtmlElement {
                 .iter()
                 .map(|(k, v)| match k.as_str() {
                     "onclick" => format!("{k}=\"{v}\""),
-                    _ => format!("{k}=\"{}\"", v.escape_default()),
+                    _ => format!("{k}=\"{}\"", sanitize_attr(v)),
                 })
                 .collect::<Vec<_>>()
                 .join(" "),
@@ -244,3 +240,9 @@ impl From<HtmlList> for Html {
 fn sanitize_html(html: &str) -> String {
     html.replace('<', "&lt;").replace('>', "&gt;")
 }
+
+/// Escapes an attribute value, which also can't contain quotes or stray
+/// ampersands since it's wrapped in double quotes
+fn sanitize_attr(value: &str) -> String {
+    sanitize_html(&value.replace('&', "&amp;")).replace('"', "&quot;")
+}
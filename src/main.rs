@@ -33,11 +33,32 @@ struct Args {
     #[arg(long, default_value_t = false)]
     overwrite: bool,
 
+    /// Whether to also document entities hidden with @internal, @private or
+    /// @hidden, for internal builds of the docs
+    #[arg(long, default_value_t = false)]
+    include_internal: bool,
+
+    /// Minimum percentage of entities that have to be documented; the build
+    /// fails if the documentation coverage is any lower
+    #[arg(long, value_parser = parse_percentage)]
+    min_coverage: Option<f64>,
+
     /// Whether to skip invoking CMake entirely, relies on existing build dir.
     #[arg(long, default_value_t = false, hide = true)]
     skip_build: bool,
 }
 
+fn parse_percentage(value: &str) -> Result<f64, String> {
+    let value = value
+        .parse::<f64>()
+        .map_err(|e| format!("{value} is not a number: {e}"))?;
+    if (0.0..=100.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!("{value} is not a percentage between 0 and 100"))
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     FlashLogger::init();
@@ -87,7 +108,13 @@ async fn main() -> Result<(), Box<dyn Error>> {
     );
 
     // Parse config
-    let conf = Config::parse(full_input, full_output, relative_output)?;
+    let conf = Config::parse(
+        full_input,
+        full_output,
+        relative_output,
+        args.include_internal,
+        args.min_coverage,
+    )?;
 
     // Build the docs
     info!(
@@ -138,3 +165,19 @@ impl FlashLogger {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentages() {
+        assert_eq!(parse_percentage("50"), Ok(50.0));
+        assert_eq!(parse_percentage("0"), Ok(0.0));
+        assert_eq!(parse_percentage("100"), Ok(100.0));
+        assert_eq!(parse_percentage("72.5"), Ok(72.5));
+        for invalid in ["-1", "101", "abc", "NaN", ""] {
+            assert!(parse_percentage(invalid).is_err(), "{invalid} was accepted");
+        }
+    }
+}
@@ -170,8 +170,10 @@ function getFullName(node) {
     const result = [node.textContent.trim()];
     while (parent.parentElement) {
         parent = parent.parentElement;
-        if (parent.tagName === 'DETAILS') {
-            result.splice(0, 0, parent.querySelector('summary').textContent.trim());
+        const summary = parent.tagName === 'DETAILS' && parent.querySelector('summary');
+        // skip the folder of a link that is itself in the folder's summary
+        if (summary && !summary.contains(node)) {
+            result.splice(0, 0, summary.textContent.trim());
         }
     }
     return result;
@@ -329,7 +331,7 @@ function updateNav() {
         });
         if (selectedNavTab() == 'entities') {
             memberFunctionsList?.forEach(fun => {
-                let funParts = fun.split('::');
+                let funParts = fun.name.split('::');
                 const name = funParts.at(-1);
                 const match = furryMatchMany(funParts, searchQuery, '::');
                 if (match) {
@@ -337,6 +339,9 @@ function updateNav() {
                     const node = document.createElement('a');
                     const url = `${FLASH_OUTPUT_URL}/classes/${funParts.join('/')}#${name.replace(/\s+\([0-9]+\)/, '')}`;
                     node.setAttribute('href', url);
+                    if (fun.deprecated) {
+                        node.classList.add('deprecated');
+                    }
                     node.addEventListener('click', e => {
                         navigate(url);
                         e.preventDefault();
@@ -467,8 +472,30 @@ async function buildNav() {
             let elem = document.createElement("a");
             elem.onclick = () => { return navigate(data.url); };
             elem.href = data.url;
+            if (data.deprecated) {
+                elem.classList.add("deprecated");
+            }
             buildIconInto(elem, data.icon);
             elem.insertAdjacentText('beforeend', data.name);
+
+            // links with nested items (like classes with member types) are
+            // shown as a folder with the link in its summary
+            if (data.items && data.items.length) {
+                let details = document.createElement("details");
+
+                let summary = document.createElement("summary");
+                let icon = document.createElement("i");
+                icon.setAttribute("data-feather", "chevron-right");
+                summary.appendChild(icon);
+                summary.appendChild(elem);
+                details.appendChild(summary);
+
+                let div = document.createElement("div");
+                data.items.map(buildNavFor).forEach(x => div.appendChild(x));
+                details.appendChild(div);
+
+                return details;
+            }
             return elem;
         }
     }